@@ -0,0 +1,566 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::export;
+use crate::growth::CollisionPolicy;
+use crate::render::Backend;
+use crate::species::Species;
+use crate::styles::BonsaiStyle;
+use crate::weather::Weather;
+use crate::Config;
+
+pub enum Command {
+    Grow(Box<Config>),
+    Help,
+}
+
+#[derive(Debug)]
+pub enum ArgError {
+    UnknownOption(String),
+    MissingValue(String),
+    UnexpectedValue(String),
+    InvalidValue { option: String, value: String, reason: &'static str },
+    UnexpectedArgument(String),
+    Conflict(&'static str, &'static str),
+    // the first option does nothing without the second
+    Requires(&'static str, &'static str),
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgError::UnknownOption(opt) => write!(f, "unrecognized option '{}'", opt),
+            ArgError::MissingValue(opt) => write!(f, "option '{}' requires an argument", opt),
+            ArgError::UnexpectedValue(opt) => write!(f, "option '{}' doesn't allow an argument", opt),
+            ArgError::InvalidValue { option, value, reason } => write!(f, "invalid value '{}' for '{}': {}", value, option, reason),
+            ArgError::UnexpectedArgument(arg) => write!(f, "unexpected argument '{}'", arg),
+            ArgError::Conflict(a, b) => write!(f, "'{}' and '{}' can't be used together", a, b),
+            ArgError::Requires(a, b) => write!(f, "'{}' needs '{}'", a, b),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum ValueKind {
+    None,
+    Required,
+    Optional,
+}
+
+// (short, long, value kind) for every option print_help advertises
+const OPTIONS: &[(Option<char>, &str, ValueKind)] = &[
+    (Some('l'), "live", ValueKind::None),
+    (Some('t'), "time", ValueKind::Required),
+    (Some('i'), "infinite", ValueKind::None),
+    (Some('w'), "wait", ValueKind::Required),
+    (Some('S'), "screensaver", ValueKind::None),
+    (Some('m'), "message", ValueKind::Required),
+    (Some('b'), "base", ValueKind::Required),
+    (Some('c'), "leaf", ValueKind::Required),
+    (Some('M'), "multiplier", ValueKind::Required),
+    (Some('L'), "life", ValueKind::Required),
+    (Some('p'), "print", ValueKind::None),
+    (Some('s'), "seed", ValueKind::Required),
+    (Some('W'), "save", ValueKind::Optional),
+    (Some('C'), "load", ValueKind::Optional),
+    (Some('v'), "verbose", ValueKind::None),
+    (Some('h'), "help", ValueKind::None),
+    (None, "rules", ValueKind::Required),
+    (None, "style", ValueKind::Required),
+    (None, "species", ValueKind::Required),
+    (None, "wind", ValueKind::Required),
+    (None, "lean", ValueKind::Required),
+    (None, "gusts", ValueKind::None),
+    (None, "collisions", ValueKind::Required),
+    (None, "auto-scale", ValueKind::None),
+    (None, "trees", ValueKind::Required),
+    (None, "forest", ValueKind::None),
+    (None, "blossoms", ValueKind::Optional),
+    (None, "fruit", ValueKind::Optional),
+    (None, "roots", ValueKind::Optional),
+    (None, "seasons", ValueKind::Optional),
+    (None, "persist", ValueKind::None),
+    (None, "growth-rate", ValueKind::Required),
+    (None, "max-branches", ValueKind::Required),
+    (None, "prune", ValueKind::None),
+    (None, "weather", ValueKind::Required),
+    (None, "backend", ValueKind::Required),
+    (None, "headless", ValueKind::None),
+    (None, "width", ValueKind::Required),
+    (None, "height", ValueKind::Required),
+    (None, "export-svg", ValueKind::Required),
+    (None, "export-png", ValueKind::Required),
+    (None, "font", ValueKind::Required),
+    (None, "cell-size", ValueKind::Required),
+    (None, "background", ValueKind::Required),
+];
+
+fn lookup_short(c: char) -> Option<(&'static str, ValueKind)> {
+    OPTIONS.iter().find(|(s, _, _)| *s == Some(c)).map(|(_, l, k)| (*l, *k))
+}
+
+fn lookup_long(name: &str) -> Option<(&'static str, ValueKind)> {
+    OPTIONS.iter().find(|(_, l, _)| *l == name).map(|(_, l, k)| (*l, *k))
+}
+
+fn parse_num<T: FromStr>(option: &str, value: &str) -> Result<T, ArgError> {
+    value.trim().parse().map_err(|_| ArgError::InvalidValue {
+        option: option.to_string(),
+        value: value.to_string(),
+        reason: "not a number",
+    })
+}
+
+fn parse_ranged<T: FromStr + PartialOrd>(option: &str, value: &str, min: T, max: T, reason: &'static str) -> Result<T, ArgError> {
+    let n: T = parse_num(option, value)?;
+    // written so that nan, which compares false to everything, is out of range too
+    if !(n >= min && n <= max) {
+        return Err(ArgError::InvalidValue { option: option.to_string(), value: value.to_string(), reason });
+    }
+    Ok(n)
+}
+
+// default leaf, same as cbonsai unless the species has its own. only if no --leaf was given
+pub fn default_leaves(conf: &mut Config) {
+    if conf.leaves_size == 0 {
+        let leaves = conf.species.map_or("&", |s| s.leaves());
+        parse_leaves(conf, leaves);
+    }
+}
+
+fn parse_leaves(conf: &mut Config, value: &str) {
+    conf.leaves_size = 0;
+    for token in value.split(',') {
+        if conf.leaves_size as usize >= conf.leaves.len() {
+            break;
+        }
+        // each token is a character, empty tokens are skipped
+        if let Some(c) = token.chars().next() {
+            conf.leaves[conf.leaves_size as usize] = c;
+            conf.leaves_size += 1;
+        }
+    }
+}
+
+// apply a single option (by its long name) to conf, `name` is the option as the user typed it
+fn apply(conf: &mut Config, opt: &str, name: &str, value: Option<String>) -> Result<bool, ArgError> {
+    let value_str = value.as_deref().unwrap_or("");
+
+    match opt {
+        "live" => conf.live = true,
+        "time" => {
+            let t: f32 = parse_num(name, value_str)?;
+            if t <= 0.0 || !t.is_finite() {
+                return Err(ArgError::InvalidValue { option: name.to_string(), value: value_str.to_string(), reason: "step time must be larger than 0" });
+            }
+            conf.time_step = t;
+        },
+        "infinite" => conf.infinite = 1,
+        "wait" => {
+            let t: f32 = parse_num(name, value_str)?;
+            if t < 0.0 || !t.is_finite() {
+                return Err(ArgError::InvalidValue { option: name.to_string(), value: value_str.to_string(), reason: "wait time must not be negative" });
+            }
+            conf.time_wait = t;
+        },
+        "screensaver" => {
+            conf.live = true;
+            conf.infinite = 1;
+            conf.save = true;
+            conf.load = true;
+            conf.screensaver = true;
+        },
+        "message" => conf.message = value_str.to_string(),
+        "base" => conf.base_type = parse_ranged(name, value_str, 0, 2, "base must be 0, 1 or 2")?,
+        "leaf" => {
+            // a leaf is one cell of the tree, anything longer would be cut to its first character
+            if value_str.split(',').any(|token| token.chars().count() > 1) {
+                return Err(ArgError::InvalidValue { option: name.to_string(), value: value_str.to_string(), reason: "every leaf must be a single character" });
+            }
+            parse_leaves(conf, value_str);
+            if conf.leaves_size == 0 {
+                return Err(ArgError::InvalidValue { option: name.to_string(), value: value_str.to_string(), reason: "no leaves given" });
+            }
+        },
+        // 0 would give every shoot its parent's whole life, and the shoots theirs, without end
+        "multiplier" => conf.multiplier = parse_ranged(name, value_str, 1, 20, "multiplier must be between 1 and 20")?,
+        "life" => conf.life_start = parse_ranged(name, value_str, 0, 200, "life must be between 0 and 200")?,
+        "print" => conf.print_tree = 1,
+        "seed" => conf.seed = parse_num(name, value_str)?,
+        "save" => {
+            conf.save = true;
+            if let Some(file) = value {
+                conf.save_file = file;
+            }
+        },
+        "load" => {
+            conf.load = true;
+            if let Some(file) = value {
+                conf.load_file = file;
+            }
+        },
+        "verbose" => conf.verbosity += 1,
+        "help" => return Ok(true),
+        "rules" => conf.rules_file = value_str.to_string(),
+        "style" => {
+            conf.style = Some(BonsaiStyle::from_name(value_str).ok_or_else(|| ArgError::InvalidValue {
+                option: name.to_string(),
+                value: value_str.to_string(),
+                reason: "style must be formal-upright, informal-upright, cascade, windswept or literati",
+            })?);
+        },
+        "species" => {
+            conf.species = Some(Species::from_name(value_str).ok_or_else(|| ArgError::InvalidValue {
+                option: name.to_string(),
+                value: value_str.to_string(),
+                reason: "species must be pine, maple, willow, cherry or juniper",
+            })?);
+        },
+        "wind" => conf.wind = parse_ranged(name, value_str, -1.0, 1.0, "wind must be between -1.0 and 1.0")?,
+        "lean" => conf.lean = parse_ranged(name, value_str, -1.0, 1.0, "lean must be between -1.0 and 1.0")?,
+        "gusts" => conf.gusts = true,
+        "auto-scale" => conf.auto_scale = true,
+        "trees" => conf.trees = parse_ranged(name, value_str, 1, 32, "trees must be between 1 and 32")?,
+        // 0 trees: as many as the terminal fits
+        "forest" => conf.trees = 0,
+        "blossoms" => conf.blossoms = match value {
+            Some(v) => parse_ranged(name, &v, 0.0, 1.0, "blossom density must be between 0.0 and 1.0")?,
+            None => 0.15,
+        },
+        "fruit" => conf.fruit = match value {
+            Some(v) => parse_ranged(name, &v, 0.0, 1.0, "fruit density must be between 0.0 and 1.0")?,
+            None => 0.05,
+        },
+        "roots" => conf.roots = match value {
+            Some(v) => parse_ranged(name, &v, 0, 8, "roots must be between 0 and 8")?,
+            None => 4,
+        },
+        "seasons" => conf.seasons = match value {
+            Some(v) => parse_ranged(name, &v, 1.0, 86400.0, "a year must take between 1 and 86400 seconds")?,
+            None => 120.0,
+        },
+        "persist" => {
+            conf.persist = true;
+            conf.save = true;
+            conf.load = true;
+        },
+        "growth-rate" => conf.growth_rate = parse_ranged(name, value_str, 0.0, 10000.0, "growth rate must be between 0 and 10000 branches per hour")?,
+        "max-branches" => conf.max_branches = parse_ranged(name, value_str, 1, i32::MAX, "max branches must be at least 1")?,
+        "prune" => conf.prune = true,
+        "headless" => conf.headless = true,
+        "width" => conf.width = parse_ranged(name, value_str, 1, 10000, "width must be between 1 and 10000")?,
+        "height" => conf.height = parse_ranged(name, value_str, 1, 10000, "height must be between 1 and 10000")?,
+        "export-svg" => conf.export_svg = value_str.to_string(),
+        "export-png" => conf.export_png = value_str.to_string(),
+        "font" => conf.font = value_str.to_string(),
+        "cell-size" => {
+            let invalid = || ArgError::InvalidValue {
+                option: name.to_string(),
+                value: value_str.to_string(),
+                reason: "cell size must be WIDTHxHEIGHT, 1 to 200 pixels each",
+            };
+            let (width, height) = value_str.trim().split_once('x').ok_or_else(invalid)?;
+            let width: i32 = width.parse().map_err(|_| invalid())?;
+            let height: i32 = height.parse().map_err(|_| invalid())?;
+            if !(1..=200).contains(&width) || !(1..=200).contains(&height) {
+                return Err(invalid());
+            }
+            conf.cell_size = Some((width, height));
+        },
+        "background" => {
+            conf.background = export::parse_color(value_str).ok_or_else(|| ArgError::InvalidValue {
+                option: name.to_string(),
+                value: value_str.to_string(),
+                reason: "background must be a #rrggbb color or none",
+            })?;
+        },
+        "weather" => {
+            conf.weather = Weather::from_name(value_str).ok_or_else(|| ArgError::InvalidValue {
+                option: name.to_string(),
+                value: value_str.to_string(),
+                reason: "weather must be rain, snow or none",
+            })?;
+        },
+        "backend" => {
+            conf.backend = Backend::from_name(value_str).ok_or_else(|| ArgError::InvalidValue {
+                option: name.to_string(),
+                value: value_str.to_string(),
+                reason: "backend must be ncurses, ansi or plain",
+            })?;
+            if conf.backend == Backend::Ncurses && !cfg!(feature = "ncurses") {
+                return Err(ArgError::InvalidValue {
+                    option: name.to_string(),
+                    value: value_str.to_string(),
+                    reason: "rbonsai was built without ncurses",
+                });
+            }
+        },
+        "collisions" => {
+            conf.collisions = CollisionPolicy::from_name(value_str).ok_or_else(|| ArgError::InvalidValue {
+                option: name.to_string(),
+                value: value_str.to_string(),
+                reason: "collisions must be overwrite, skip, leaves or steer",
+            })?;
+        },
+        _ => return Err(ArgError::UnknownOption(name.to_string())),
+    }
+
+    Ok(false)
+}
+
+// parses getopt_long style arguments (without the program name) into a Config:
+//   short flags may be bundled (-li), short values may be attached or separate (-L64, -L 64),
+//   long values may use = or a separate argument (--life=64, --life 64),
+//   optional values (--save, --load, --blossoms, --fruit, --roots, --seasons) must be attached (-Wfile, --save=file)
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, ArgError> {
+    let mut conf = Config::default();
+    let mut args = args.into_iter();
+    let mut help = false;
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            if let Some(extra) = args.next() {
+                return Err(ArgError::UnexpectedArgument(extra));
+            }
+            break;
+        }
+
+        if let Some(long) = arg.strip_prefix("--") {
+            let (name, attached) = match long.split_once('=') {
+                Some((n, v)) => (n, Some(v.to_string())),
+                None => (long, None),
+            };
+
+            let (opt, kind) = lookup_long(name).ok_or_else(|| ArgError::UnknownOption(format!("--{}", name)))?;
+            let option = format!("--{}", name);
+
+            let value = match kind {
+                ValueKind::None if attached.is_some() => return Err(ArgError::UnexpectedValue(option)),
+                ValueKind::None | ValueKind::Optional => attached,
+                ValueKind::Required => match attached {
+                    Some(v) => Some(v),
+                    None => Some(args.next().ok_or_else(|| ArgError::MissingValue(option.clone()))?),
+                },
+            };
+
+            help |= apply(&mut conf, opt, &option, value)?;
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            for (i, c) in shorts.char_indices() {
+                let (opt, kind) = lookup_short(c).ok_or_else(|| ArgError::UnknownOption(format!("-{}", c)))?;
+                let option = format!("-{}", c);
+                let rest = &shorts[i + c.len_utf8()..];
+
+                if kind == ValueKind::None {
+                    help |= apply(&mut conf, opt, &option, None)?;
+                    continue;
+                }
+
+                // the rest of the bundle is this option's value
+                let value = if !rest.is_empty() {
+                    Some(rest.to_string())
+                } else if kind == ValueKind::Required {
+                    Some(args.next().ok_or_else(|| ArgError::MissingValue(option.clone()))?)
+                } else {
+                    None
+                };
+
+                help |= apply(&mut conf, opt, &option, value)?;
+                break;
+            }
+        } else {
+            return Err(ArgError::UnexpectedArgument(arg));
+        }
+    }
+
+    if help {
+        return Ok(Command::Help);
+    }
+
+    // every new tree of an infinite run has its own seed, which would be saved over the living tree
+    if conf.persist {
+        if conf.screensaver {
+            return Err(ArgError::Conflict("--persist", "--screensaver"));
+        }
+        if conf.infinite != 0 {
+            return Err(ArgError::Conflict("--persist", "--infinite"));
+        }
+    }
+
+    // gusts are changes in the wind, with none there is nothing to gust
+    if conf.gusts && conf.wind == 0.0 {
+        return Err(ArgError::Requires("--gusts", "--wind"));
+    }
+
+    // a style is a built-in rules file, so only one of them can win
+    if conf.style.is_some() && !conf.rules_file.is_empty() {
+        return Err(ArgError::Conflict("--style", "--rules"));
+    }
+
+    // exports are of the finished tree, an infinite run never has one
+    let exports = [(!conf.export_svg.is_empty(), "--export-svg"), (!conf.export_png.is_empty(), "--export-png")];
+    if let Some((_, export)) = exports.iter().find(|(on, _)| *on) {
+        if conf.screensaver {
+            return Err(ArgError::Conflict(export, "--screensaver"));
+        }
+        if conf.infinite != 0 {
+            return Err(ArgError::Conflict(export, "--infinite"));
+        }
+    }
+
+    // builds without ncurses draw with escape codes instead
+    if !cfg!(feature = "ncurses") && !conf.headless && conf.backend == Backend::Ncurses {
+        conf.backend = Backend::Ansi;
+    }
+
+    // headless there is no screen to draw on as it grows, only the finished tree comes out
+    if conf.headless && conf.backend == Backend::Ansi {
+        return Err(ArgError::Conflict("--headless", "--backend=ansi"));
+    }
+
+    // only ncurses reads keys and keeps the screen going after the tree is done
+    if conf.headless || conf.backend != Backend::Ncurses {
+        let backend = match conf.backend {
+            _ if conf.headless => "--headless",
+            Backend::Ansi => "--backend=ansi",
+            _ => "--backend=plain",
+        };
+        let interactive = [
+            (conf.screensaver, "--screensaver"),
+            (conf.infinite != 0, "--infinite"),
+            (conf.prune, "--prune"),
+            (conf.seasons > 0.0, "--seasons"),
+            (conf.weather.is_some(), "--weather"),
+        ];
+        if let Some((_, option)) = interactive.iter().find(|(on, _)| *on) {
+            return Err(ArgError::Conflict(backend, option));
+        }
+    }
+
+    default_leaves(&mut conf);
+
+    Ok(Command::Grow(Box::new(conf)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Config, ArgError> {
+        match parse_args(args.iter().map(|a| a.to_string()))? {
+            Command::Grow(conf) => Ok(*conf),
+            Command::Help => panic!("{:?} asked for help", args),
+        }
+    }
+
+    #[test]
+    fn bundled_flags() {
+        let conf = parse(&["-lpv"]).unwrap();
+        assert!(conf.live);
+        assert_eq!(conf.print_tree, 1);
+        assert_eq!(conf.verbosity, 1);
+    }
+
+    #[test]
+    fn the_rest_of_a_bundle_is_the_value() {
+        let conf = parse(&["-pL20"]).unwrap();
+        assert_eq!(conf.print_tree, 1);
+        assert_eq!(conf.life_start, 20);
+    }
+
+    #[test]
+    fn required_values() {
+        for args in [&["-L64"][..], &["-L", "64"], &["--life=64"], &["--life", "64"]] {
+            assert_eq!(parse(args).unwrap().life_start, 64, "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn optional_values_must_be_attached() {
+        let conf = parse(&["-Wtree.dat"]).unwrap();
+        assert!(conf.save);
+        assert_eq!(conf.save_file, "tree.dat");
+
+        let conf = parse(&["--blossoms"]).unwrap();
+        assert_eq!(conf.blossoms, 0.15);
+        let conf = parse(&["--blossoms=0.5"]).unwrap();
+        assert_eq!(conf.blossoms, 0.5);
+
+        assert!(matches!(parse(&["-W", "tree.dat"]), Err(ArgError::UnexpectedArgument(a)) if a == "tree.dat"));
+    }
+
+    #[test]
+    fn help() {
+        assert!(matches!(parse_args(["-h".to_string()]), Ok(Command::Help)));
+        assert!(matches!(parse_args(["-L20".to_string(), "--help".to_string()]), Ok(Command::Help)));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(parse(&["-e"]), Err(ArgError::UnknownOption(o)) if o == "-e"));
+        assert!(matches!(parse(&["--nope"]), Err(ArgError::UnknownOption(o)) if o == "--nope"));
+        assert!(matches!(parse(&["-L"]), Err(ArgError::MissingValue(o)) if o == "-L"));
+        assert!(matches!(parse(&["--print=yes"]), Err(ArgError::UnexpectedValue(o)) if o == "--print"));
+        assert!(matches!(parse(&["-M", "0"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["--life=201"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["-t", "x"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["tree"]), Err(ArgError::UnexpectedArgument(a)) if a == "tree"));
+        assert!(matches!(parse(&["--", "tree"]), Err(ArgError::UnexpectedArgument(a)) if a == "tree"));
+    }
+
+    #[test]
+    fn conflicts() {
+        assert!(matches!(parse(&["--persist", "-S"]), Err(ArgError::Conflict("--persist", "--screensaver"))));
+        assert!(matches!(parse(&["--style=cascade", "--rules=x.toml"]), Err(ArgError::Conflict("--style", "--rules"))));
+        assert!(matches!(parse(&["--headless", "--prune"]), Err(ArgError::Conflict("--headless", "--prune"))));
+    }
+
+    #[test]
+    fn wind_must_be_a_number() {
+        assert_eq!(parse(&["--wind=-0.5"]).unwrap().wind, -0.5);
+        assert!(matches!(parse(&["--wind=nan"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["--lean=NaN"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["--lean=inf"]), Err(ArgError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn densities_must_be_numbers() {
+        assert!(matches!(parse(&["--blossoms=nan"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["--fruit=nan"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["--fruit=1.5"]), Err(ArgError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn a_year_takes_some_seconds() {
+        assert_eq!(parse(&["--seasons"]).unwrap().seasons, 120.0);
+        for year in ["--seasons=nan", "--seasons=0", "--seasons=inf", "--seasons=-5"] {
+            assert!(matches!(parse(&[year]), Err(ArgError::InvalidValue { .. })), "{}", year);
+        }
+    }
+
+    #[test]
+    fn growth_rate_must_be_a_number() {
+        assert_eq!(parse(&["--growth-rate=0.5"]).unwrap().growth_rate, 0.5);
+        assert!(matches!(parse(&["--growth-rate=nan"]), Err(ArgError::InvalidValue { .. })));
+        assert!(matches!(parse(&["--growth-rate=inf"]), Err(ArgError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn gusts_need_wind() {
+        assert!(matches!(parse(&["--gusts"]), Err(ArgError::Requires("--gusts", "--wind"))));
+        assert!(matches!(parse(&["--gusts", "--lean=0.5"]), Err(ArgError::Requires("--gusts", "--wind"))));
+        assert!(parse(&["--gusts", "--wind=0.5"]).unwrap().gusts);
+    }
+
+    #[test]
+    fn leaves() {
+        let conf = parse(&[]).unwrap();
+        assert_eq!(conf.leaves_size, 1);
+        assert_eq!(conf.leaves[0], '&');
+
+        let conf = parse(&["-c", "a,b"]).unwrap();
+        assert_eq!(conf.leaves_size, 2);
+        assert_eq!(&conf.leaves[..2], &['a', 'b']);
+
+        assert!(matches!(parse(&["-c", "ab,cd"]), Err(ArgError::InvalidValue { .. })));
+    }
+}
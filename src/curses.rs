@@ -0,0 +1,697 @@
+// the ncurses front end, everything that needs libncurses
+
+use ncurses::*;
+use std::process::exit;
+use std::thread;
+use std::time::Duration;
+
+use rbonsai::{render, Config};
+use rbonsai::growth::{Counters, Growth, GrowthEvent, GrowthListener};
+use rbonsai::render::{NcursesRenderer, Renderer, TextRenderer};
+use rbonsai::rules::GrowthRules;
+use rbonsai::scene::{draw_scene, grow_step, plant_forest, pot_contains, pot_rects, pot_slots, scale_to_window, total_counters};
+use rbonsai::seasons::Seasons;
+use rbonsai::tree::{Style, Tree};
+use rbonsai::weather::Sky;
+
+use crate::{pick_seed, wrap_up};
+
+struct NcursesObjects {
+    // one pot per tree, slots are the (x, width) column range each tree grows in
+    base_wins: Vec<WINDOW>,
+    slots: Vec<(i32, i32)>,
+    tree_win: Option<WINDOW>,
+    message_border_win: Option<WINDOW>,
+    message_win: Option<WINDOW>,
+    // --weather, the whole screen over the trees and pots but under the message
+    weather_win: Option<WINDOW>,
+
+    base_panels: Vec<PANEL>,
+    tree_panel: Option<PANEL>,
+    message_border_panel: Option<PANEL>,
+    message_panel: Option<PANEL>,
+    weather_panel: Option<PANEL>,
+}
+
+#[allow(unused_variables)]
+fn quit(conf: &Config, objects: &NcursesObjects, return_code: i32) {
+    // the message windows only exist when a message was given
+    for panel in [objects.tree_panel, objects.message_border_panel, objects.message_panel, objects.weather_panel].into_iter().flatten() {
+        del_panel(panel);
+    }
+    for panel in &objects.base_panels {
+        del_panel(*panel);
+    }
+
+    for win in [objects.tree_win, objects.message_border_win, objects.message_win, objects.weather_win].into_iter().flatten() {
+        delwin(win);
+    }
+    for win in &objects.base_wins {
+        delwin(*win);
+    }
+
+    // free conf.save_file and conf.load_file
+
+    exit(return_code)
+}
+
+// screen is what was on it when the tree was done, None when quitting before that
+fn finish(conf: &Config, my_counters: &Counters, screen: Option<&TextRenderer>) {
+    clear();
+    refresh();
+    endwin();
+
+    // -p
+    if let Some(screen) = screen.filter(|_| conf.print_tree != 0) {
+        print!("{}", screen.text());
+    }
+
+    wrap_up(conf, my_counters, screen);
+}
+
+fn draw_wins(conf: &Config, objects: &mut NcursesObjects) {
+    let base_type = conf.base_type;
+    let (base_width, base_height) = render::base_size(base_type);
+    let mut rows = 0;
+    let mut cols = 0;
+
+    getmaxyx(stdscr(), &mut rows, &mut cols);
+    let base_origin_y = rows - base_height;
+    objects.slots = pot_slots(conf, cols, base_width);
+
+    // windows from a previous tree are replaced in their panels below, then deleted
+    let mut old_wins = std::mem::take(&mut objects.base_wins);
+    old_wins.extend(objects.tree_win);
+    old_wins.extend(objects.weather_win.take());
+
+    for &(slot_x, slot_width) in &objects.slots {
+        let base_origin_x = slot_x + (slot_width / 2) - (base_width / 2);
+        objects.base_wins.push(newwin(base_height, base_width, base_origin_y, base_origin_x));
+    }
+    // a cascade hangs down beside the pot, so its window reaches the bottom and the pot is drawn over it
+    let hangs_below_rim = conf.style.is_some_and(|s| s.hangs_below_rim());
+    let tree_height = if hangs_below_rim { rows } else { rows - base_height };
+    objects.tree_win = Some(newwin(tree_height, cols, 0, 0));
+
+    // the number of pots can change with the terminal size in --forest mode
+    for (i, &w) in objects.base_wins.iter().enumerate() {
+        if let Some(&p) = objects.base_panels.get(i) {
+            replace_panel(p, w);
+        } else {
+            objects.base_panels.push(new_panel(w));
+        }
+    }
+    for p in objects.base_panels.split_off(objects.base_wins.len()) {
+        del_panel(p);
+    }
+
+    if let Some(p) = objects.tree_panel {
+        let w = objects.tree_win.expect("could not get tree_win");
+        replace_panel(p, w);
+    } else {
+        objects.tree_panel = Some(new_panel(objects.tree_win.expect("could not get tree_win")));
+    }
+
+    if hangs_below_rim {
+        for &p in &objects.base_panels {
+            top_panel(p);
+        }
+    }
+
+    // the weather is drawn over everything else, it shows the trees and pots through it (see draw_weather)
+    if conf.weather.is_some() {
+        let w = newwin(rows, cols, 0, 0);
+        objects.weather_win = Some(w);
+        if let Some(p) = objects.weather_panel {
+            replace_panel(p, w);
+        } else {
+            objects.weather_panel = Some(new_panel(w));
+        }
+        top_panel(objects.weather_panel.unwrap());
+    }
+
+    for win in old_wins {
+        delwin(win);
+    }
+
+    for &base_win in &objects.base_wins {
+        render::draw_base(&mut NcursesRenderer::new(base_win), 0, 0, base_type);
+    }
+}
+
+// what a key pressed while the tree grows asks for
+enum KeyAction {
+    Nothing,
+    Quit,
+    // bend the branch growing right now (dx, dy)
+    Wire(i32, i32),
+}
+
+fn read_key(conf: &Config) -> KeyAction {
+    let key = wgetch(stdscr());
+    if key == ERR {
+        return KeyAction::Nothing;
+    }
+
+    // any key ends a screensaver
+    if conf.screensaver || key == 'q' as i32 {
+        return KeyAction::Quit;
+    }
+
+    match key {
+        KEY_UP => KeyAction::Wire(0, -1),
+        KEY_DOWN => KeyAction::Wire(0, 1),
+        KEY_LEFT => KeyAction::Wire(-1, 0),
+        KEY_RIGHT => KeyAction::Wire(1, 0),
+        key if key == 'k' as i32 => KeyAction::Wire(0, -1),
+        key if key == 'j' as i32 => KeyAction::Wire(0, 1),
+        key if key == 'h' as i32 => KeyAction::Wire(-1, 0),
+        key if key == 'l' as i32 => KeyAction::Wire(1, 0),
+        _ => KeyAction::Nothing,
+    }
+}
+
+// for when there's nothing to wire, only quitting matters
+fn check_key_press(conf: &Config, my_counters: &Counters) -> bool {
+    if let KeyAction::Quit = read_key(conf) {
+        finish(conf, my_counters, None);
+        return true;
+    }
+    false
+}
+
+fn update_screen(time_step: f32) {
+    update_panels();
+    doupdate();
+
+    let dur = Duration::from_secs_f32(time_step);
+    thread::sleep(dur);
+}
+
+fn create_message_windows(objects: &mut NcursesObjects, message: &str) {
+    let mut max_y: i32 = 0;
+    let mut max_x: i32 = 0;
+    getmaxyx(stdscr(), &mut max_y, &mut max_x);
+
+    let (y, x, height, width) = render::message_box(message, max_y, max_x);
+
+    let old_wins = [objects.message_border_win, objects.message_win];
+
+    objects.message_border_win = Some(newwin(height + 2, width + 3, y - 1, x - 2));
+    objects.message_win = Some(newwin(height, width, y, x));
+
+    render::draw_message_border(&mut NcursesRenderer::new(objects.message_border_win.unwrap()), 1, 2, height, width);
+
+    if let Some(p) = objects.message_border_panel {
+        replace_panel(p, objects.message_border_win.unwrap());
+    } else {
+        objects.message_border_panel = Some(new_panel(objects.message_border_win.unwrap()));
+    }
+
+    if let Some(p) = objects.message_panel {
+        replace_panel(p, objects.message_win.unwrap());
+    } else {
+        objects.message_panel = Some(new_panel(objects.message_win.unwrap()));
+    }
+
+    // the message stays on top of the pots and the weather every time the windows are made again
+    top_panel(objects.message_border_panel.unwrap());
+    top_panel(objects.message_panel.unwrap());
+
+    for win in old_wins.into_iter().flatten() {
+        delwin(win);
+    }
+}
+
+fn draw_message(conf: &Config, objects: &mut NcursesObjects) {
+    if conf.message.is_empty() {
+        return;
+    }
+
+    create_message_windows(objects, &conf.message);
+
+    let message_win = objects.message_win.unwrap();
+    let (height, width) = (getmaxy(message_win), getmaxx(message_win));
+    let lines = render::layout_message(&conf.message, height, width);
+    render::draw_message_text(&mut NcursesRenderer::new(message_win), 0, 0, &lines);
+}
+
+fn init(conf: &Config, objects: &mut NcursesObjects) {
+    savetty();
+    initscr();
+    noecho();
+    cbreak();
+    nodelay(stdscr(), true);
+    curs_set(CURSOR_VISIBILITY::CURSOR_INVISIBLE);
+
+    // arrow keys wire branches in live mode
+    if conf.live && !conf.screensaver {
+        keypad(stdscr(), true);
+    }
+
+    if has_colors() {
+        let mut bg = COLOR_BLACK;
+        start_color();
+        if use_default_colors() != ERR { bg = -1; }
+
+        for i in 0..16 {
+            init_pair(i, i, bg);
+        }
+
+        // restrict color palette in non-256color terminals (e.g. screen or linux)
+        if COLORS() < 256 {
+            init_pair(8, 7, bg); // gray will look white
+            init_pair(9, 1, bg);
+            init_pair(10, 2, bg);
+            init_pair(11, 3, bg);
+            init_pair(12, 4, bg);
+            init_pair(13, 5, bg);
+            init_pair(14, 6, bg);
+            init_pair(15, 7, bg);
+        }
+    } else {
+        println!("Warning: terminal does not have color support.");
+    }
+
+    // define and draw windows, then create panels
+    draw_wins(conf, objects);
+    draw_message(conf, objects);
+}
+
+fn draw_cell(win: WINDOW, y: i32, x: i32, glyph: char, style: Style) {
+    let mut renderer = NcursesRenderer::new(win);
+    renderer.set_style(style);
+    renderer.put_cell(y, x, glyph);
+}
+
+// draws every cell the engine places into the tree window, x is where the tree's slot starts.
+// roots that grow into the pot are drawn over the base art instead
+struct TreeWinRenderer {
+    win: WINDOW,
+    x: i32,
+    base_win: Option<WINDOW>,
+}
+
+impl GrowthListener for TreeWinRenderer {
+    fn on_event(&mut self, event: &GrowthEvent) {
+        if let GrowthEvent::CellPlaced { y, x, glyph, branch_type, style } = *event {
+            let x = self.x + x;
+
+            if let Some(base_win) = self.base_win.filter(|_| branch_type.is_root()) {
+                let (mut base_y, mut base_x) = (0, 0);
+                getbegyx(base_win, &mut base_y, &mut base_x);
+                if y >= base_y {
+                    draw_cell(base_win, y - base_y, x - base_x, glyph, style);
+                    return;
+                }
+            }
+
+            if y < getmaxy(self.win) {
+                draw_cell(self.win, y, x, glyph, style);
+            }
+        }
+    }
+}
+
+// -v debug output in the top left of the tree window
+struct VerboseOverlay {
+    win: WINDOW,
+    shoots: i32,
+}
+
+impl GrowthListener for VerboseOverlay {
+    fn on_event(&mut self, event: &GrowthEvent) {
+        match *event {
+            GrowthEvent::ShootSpawned { .. } => {
+                self.shoots += 1;
+                mvwprintw(self.win, 4, 5, format!("shoots: {}", self.shoots).as_str());
+            },
+            GrowthEvent::BranchMoved { branch_type, dx, dy, .. } => {
+                mvwprintw(self.win, 5, 5, format!("dx: {}", dx).as_str());
+                mvwprintw(self.win, 6, 5, format!("dy: {}", dy).as_str());
+                mvwprintw(self.win, 7, 5, format!("type: {}", branch_type).as_str());
+            },
+            _ => (),
+        }
+    }
+}
+
+fn grow_tree(conf: &Config, objects: &NcursesObjects, rules: &dyn GrowthRules, my_counters: &mut Counters, sky: &mut Option<Sky>) -> Tree {
+    let tree_win = objects.tree_win.unwrap();
+    let mut max_y: i32 = 0;
+    let mut max_x: i32 = 0;
+    getmaxyx(tree_win, &mut max_y, &mut max_x);
+
+    // the trees cover the whole screen, pots included, so roots can grow into them
+    let rows = getmaxy(stdscr());
+
+    // every pot grows its own tree in its slot
+    let mut pots = Vec::new();
+    let mut renderers = Vec::new();
+    for (i, &slot) in objects.slots.iter().enumerate() {
+        let mut pot = None;
+
+        let base_win = objects.base_wins.get(i).copied().filter(|w| !w.is_null());
+        if let Some(base_win) = base_win {
+            let (mut base_y, mut base_x, mut base_h, mut base_w) = (0, 0, 0, 0);
+            getbegyx(base_win, &mut base_y, &mut base_x);
+            getmaxyx(base_win, &mut base_h, &mut base_w);
+            if base_h > 0 && base_w > 0 {
+                pot = Some((base_y, base_x, base_h, base_w));
+            }
+        }
+
+        pots.push(pot);
+        renderers.push(TreeWinRenderer { win: tree_win, x: slot.0, base_win: base_win.filter(|_| pot.is_some()) });
+    }
+    let mut growths = plant_forest(conf, rules, rows, &objects.slots, &pots, max_y);
+
+    if conf.verbosity > 0 {
+        mvwprintw(tree_win, 2, 5, format!("maxX: {:03}, maxY: {:03}", max_x, max_y).as_str());
+        mvwprintw(tree_win, 3, 5, format!("seed: {}", conf.seed).as_str());
+    }
+
+    let mut overlay = VerboseOverlay { win: tree_win, shoots: 0 };
+
+    // the trees take turns, one step each per frame, so they grow side by side in live mode
+    while grow_step(conf, &mut growths, |i, growth| {
+        if conf.verbosity > 0 && i == 0 {
+            growth.notify(&mut [&mut renderers[i], &mut overlay]);
+        } else {
+            growth.notify(&mut [&mut renderers[i]]);
+        }
+    }) {
+        *my_counters = total_counters(&growths);
+
+        match read_key(conf) {
+            KeyAction::Quit => {
+                finish(conf, my_counters, None);
+                quit(conf, objects, 0);
+            },
+            // the first tree is the one being wired, the others grow on their own
+            KeyAction::Wire(dx, dy) => growths[0].wire(dx, dy),
+            KeyAction::Nothing => (),
+        }
+
+        if conf.live && !(conf.load && my_counters.branches < conf.target_branch_count) {
+            draw_weather(conf, objects, sky, true);
+            update_screen(conf.time_step);
+        }
+    }
+    *my_counters = total_counters(&growths);
+
+    if conf.prune {
+        prune_trees(conf, objects, &mut growths, &mut renderers, my_counters, sky);
+    }
+
+    // display changes
+    draw_weather(conf, objects, sky, false);
+    update_panels();
+    doupdate();
+
+    let mut forest = Tree::new(rows, max_x);
+    for (growth, &(slot_x, _)) in growths.into_iter().zip(objects.slots.iter()) {
+        forest.paste(&growth.into_tree(), slot_x);
+    }
+    forest
+}
+
+// draws the trees again from scratch, pots and all, for when cells go away instead of being drawn over
+fn redraw_trees(conf: &Config, objects: &NcursesObjects, growths: &[Growth], renderers: &mut [TreeWinRenderer]) {
+    werase(objects.tree_win.unwrap());
+    for &base_win in objects.base_wins.iter().filter(|w| !w.is_null()) {
+        werase(base_win);
+        render::draw_base(&mut NcursesRenderer::new(base_win), 0, 0, conf.base_type);
+    }
+
+    for (growth, renderer) in growths.iter().zip(renderers.iter_mut()) {
+        let tree = growth.tree();
+        for y in 0..tree.height() {
+            for x in 0..tree.width() {
+                if let Some(cell) = tree.get(y, x) {
+                    renderer.on_event(&GrowthEvent::CellPlaced { y, x, glyph: cell.glyph, branch_type: cell.branch_type, style: cell.style });
+                }
+            }
+        }
+    }
+}
+
+// --prune: a cursor over the finished trees, cutting a branch takes everything growing off it too
+// and a new bud grows back from where it started
+fn prune_trees(conf: &Config, objects: &NcursesObjects, growths: &mut [Growth], renderers: &mut [TreeWinRenderer], my_counters: &mut Counters, sky: &mut Option<Sky>) {
+    let tree_win = objects.tree_win.unwrap();
+    let mut max_y: i32 = 0;
+    let mut max_x: i32 = 0;
+    getmaxyx(tree_win, &mut max_y, &mut max_x);
+
+    // the tree (slot) under column x, and x inside it
+    let slot_at = |x: i32| objects.slots.iter().position(|&(slot_x, slot_w)| x >= slot_x && x < slot_x + slot_w);
+
+    // start at the foot of the first trunk
+    let (mut y, mut x) = match growths[0].tree().branch(0) {
+        Some(trunk) => (trunk.y, trunk.x + objects.slots[0].0),
+        None => (max_y - 1, max_x / 2),
+    };
+
+    keypad(stdscr(), true);
+    nodelay(stdscr(), false);
+
+    loop {
+        y = y.clamp(0, max_y - 1);
+        x = x.clamp(0, max_x - 1);
+
+        redraw_trees(conf, objects, growths, renderers);
+        draw_weather(conf, objects, sky, false);
+
+        // the cursor is the cell under it in reverse. it goes over the weather, which only lets the trees show
+        // through where they're drawn, so it can be seen on a blank cell too
+        let cursor_win = objects.weather_win.unwrap_or(tree_win);
+        let cell = slot_at(x).and_then(|i| growths[i].tree().get(y, x - objects.slots[i].0).copied());
+        let (glyph, style) = cell.map_or((' ', Style { color: 7, bold: false }), |c| (c.glyph, c.style));
+        wattrset(cursor_win, COLOR_PAIR(style.color) | A_REVERSE() | if style.bold { A_BOLD() } else { A_NORMAL() });
+        mvwaddstr(cursor_win, y, x, glyph.encode_utf8(&mut [0; 4]));
+        wattrset(cursor_win, A_NORMAL());
+
+        update_panels();
+        doupdate();
+
+        match wgetch(stdscr()) {
+            KEY_UP => y -= 1,
+            KEY_DOWN => y += 1,
+            KEY_LEFT => x -= 1,
+            KEY_RIGHT => x += 1,
+            key if key == 'k' as i32 => y -= 1,
+            key if key == 'j' as i32 => y += 1,
+            key if key == 'h' as i32 => x -= 1,
+            key if key == 'l' as i32 => x += 1,
+            key if key == ' ' as i32 || key == 'x' as i32 => {
+                let Some(i) = slot_at(x) else { continue };
+                if !growths[i].prune(y, x - objects.slots[i].0) { continue; }
+
+                // the cut is gone at once, the bud grows back like the tree did
+                redraw_trees(conf, objects, growths, renderers);
+                while growths[i].next().is_some() {
+                    growths[i].notify(&mut [&mut renderers[i]]);
+                    if conf.live {
+                        draw_weather(conf, objects, sky, true);
+                        update_screen(conf.time_step);
+                    }
+                }
+                growths[i].notify(&mut [&mut renderers[i]]);
+                *my_counters = total_counters(growths);
+            },
+            key if key == 'q' as i32 || key == '\n' as i32 || key == KEY_ENTER => break,
+            _ => (),
+        }
+    }
+
+    nodelay(stdscr(), true);
+}
+
+// --weather: the trees and pots are copied into the weather window and the rain or snow goes in the free cells around them.
+// tick moves the weather on a frame, otherwise it's only drawn again over whatever changed underneath
+fn draw_weather(conf: &Config, objects: &NcursesObjects, sky: &mut Option<Sky>, tick: bool) {
+    let (Some(sky), Some(weather_win)) = (sky.as_mut(), objects.weather_win) else {
+        return;
+    };
+
+    // in the same order as their panels, a cascade is drawn under the pots
+    let tree_win = objects.tree_win.unwrap();
+    let base_wins = objects.base_wins.iter().copied().filter(|w| !w.is_null());
+    werase(weather_win);
+    if conf.style.is_some_and(|s| s.hangs_below_rim()) {
+        overlay(tree_win, weather_win);
+        base_wins.for_each(|w| { overlay(w, weather_win); });
+    } else {
+        base_wins.for_each(|w| { overlay(w, weather_win); });
+        overlay(tree_win, weather_win);
+    }
+
+    // the same pots scene::grow_in_memory keeps branches out of
+    let pots = pot_rects(conf, getmaxy(stdscr()), &objects.slots);
+    let taken = |y: i32, x: i32| pot_contains(&pots, y, x) || mvwinch(weather_win, y, x) & A_CHARTEXT() != ' ' as chtype;
+    if tick {
+        sky.tick(taken);
+    }
+
+    for (y, x, glyph, style) in sky.cells(taken) {
+        draw_cell(weather_win, y, x, glyph, style);
+    }
+}
+
+const WEATHER_TICK: f32 = 0.05;
+
+// waits secs (or until key_pressed says so, forever without secs) while the weather goes on, true if a key ended it
+fn weather_wait(conf: &Config, objects: &NcursesObjects, sky: &mut Option<Sky>, secs: Option<f32>, mut key_pressed: impl FnMut() -> bool) -> bool {
+    let mut elapsed = 0.0;
+
+    while secs.is_none_or(|secs| elapsed < secs) {
+        draw_weather(conf, objects, sky, true);
+        update_panels();
+        doupdate();
+
+        timeout((WEATHER_TICK * 1000.0) as i32);
+        if key_pressed() {
+            return true;
+        }
+        elapsed += WEATHER_TICK;
+    }
+    false
+}
+
+// the finished screen in memory, for -p and the exports once the terminal is back to normal
+fn finished_screen(conf: &Config, objects: &NcursesObjects, tree: &Tree) -> TextRenderer {
+    let (rows, cols) = (getmaxy(stdscr()), getmaxx(stdscr()));
+    let mut screen = TextRenderer::new(rows, cols, true);
+    draw_scene(&mut screen, conf, &[(tree, 0)], &pot_rects(conf, rows, &objects.slots));
+    screen
+}
+
+// the finished tree through the seasons. in infinite mode for one year, otherwise until a key is pressed
+// (with -p only the season it's in is shown, -p never waits for a key). returns the tree as it was shown last
+fn show_seasons(conf: &mut Config, objects: &NcursesObjects, tree: &Tree, my_counters: &Counters, sky: &mut Option<Sky>) -> Tree {
+    const TICK: f32 = 0.1;
+
+    let tree_win = objects.tree_win.unwrap();
+    let mut seasons = Seasons::new(tree, conf.seed as u64);
+    let mut elapsed = 0.0;
+    let mut view = tree.clone();
+
+    while conf.infinite == 0 || elapsed < conf.seasons {
+        seasons.update(conf.season);
+        view = seasons.view(conf.season);
+
+        werase(tree_win);
+        for y in 0..getmaxy(tree_win).min(view.height()) {
+            for x in 0..view.width() {
+                if let Some(cell) = view.get(y, x) {
+                    draw_cell(tree_win, y, x, cell.glyph, cell.style);
+                }
+            }
+        }
+        draw_weather(conf, objects, sky, true);
+        update_panels();
+        doupdate();
+
+        if conf.infinite == 0 && conf.print_tree != 0 {
+            return view;
+        }
+
+        timeout((TICK * 1000.0) as i32);
+        if conf.infinite == 0 {
+            if wgetch(stdscr()) != ERR { return view; }
+        } else if check_key_press(conf, my_counters) {
+            quit(conf, objects, 0);
+        }
+
+        elapsed += TICK;
+        conf.season = (conf.season + TICK / conf.seasons).fract();
+    }
+
+    view
+}
+
+pub fn run(mut conf: Config, rules: &dyn GrowthRules) {
+    let mut objects = NcursesObjects {
+        base_wins: Vec::new(),
+        slots: Vec::new(),
+        tree_win: None,
+        message_border_win: None,
+        message_win: None,
+        weather_win: None,
+
+        base_panels: Vec::new(),
+        tree_panel: None,
+        message_border_panel: None,
+        message_panel: None,
+        weather_panel: None,
+    };
+
+    let mut my_counters = Counters {
+        branches: 0,
+        shoots: 0,
+        shoot_counter: 0,
+    };
+
+    // what was asked for, --auto-scale scales from these for every tree
+    let (life_start, multiplier) = (conf.life_start, conf.multiplier);
+
+    let mut sky: Option<Sky>;
+
+    let tree = loop {
+        init(&conf, &mut objects);
+        conf.eight_colors = has_colors() && COLORS() < 256;
+        if conf.auto_scale {
+            let tree_win = objects.tree_win.unwrap();
+            scale_to_window(&mut conf, getmaxy(tree_win), getmaxx(tree_win), life_start, multiplier);
+        }
+
+        // a clear sky over every new tree
+        sky = conf.weather.map(|w| Sky::new(w, getmaxy(stdscr()), getmaxx(stdscr()), conf.seed as u64));
+
+        let tree = grow_tree(&conf, &objects, rules, &mut my_counters, &mut sky);
+
+        // only the first tree replays a loaded save
+        if conf.load { conf.target_branch_count = 0; }
+
+        if conf.seasons > 0.0 {
+            // -p and the exports show the season the tree was left in, not its summer leaves
+            let view = show_seasons(&mut conf, &objects, &tree, &my_counters, &mut sky);
+            if conf.infinite == 0 {
+                let screen = finished_screen(&conf, &objects, &view);
+                finish(&conf, &my_counters, Some(&screen));
+                quit(&conf, &objects, 0);
+            }
+        }
+
+        if conf.infinite == 0 { break tree; }
+
+        // the weather goes on between trees
+        let quitting = match &mut sky {
+            Some(_) => weather_wait(&conf, &objects, &mut sky, Some(conf.time_wait), || check_key_press(&conf, &my_counters)),
+            None => {
+                timeout((conf.time_wait * 1000.0) as i32);
+                check_key_press(&conf, &my_counters)
+            },
+        };
+        if quitting {
+            quit(&conf, &objects, 0);
+        }
+
+        conf.seed = pick_seed();
+    };
+
+    // -p goes straight to printing, like cbonsai, so it can run from a shell's startup
+    if conf.print_tree == 0 {
+        if sky.is_some() {
+            weather_wait(&conf, &objects, &mut sky, None, || wgetch(stdscr()) != ERR);
+        } else {
+            wgetch(objects.tree_win.unwrap());
+        }
+    }
+
+    let screen = finished_screen(&conf, &objects, &tree);
+    finish(&conf, &my_counters, Some(&screen));
+
+    quit(&conf, &objects, 0);
+}
\ No newline at end of file
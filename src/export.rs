@@ -0,0 +1,318 @@
+use crate::Config;
+use crate::font;
+use crate::render::{Renderer, TextRenderer};
+use crate::tree::Style;
+
+// the 16 colors the color pairs are made of, as xterm shows them
+pub const PALETTE: [[u8; 3]; 16] = [
+    [0x00, 0x00, 0x00],
+    [0xcd, 0x00, 0x00],
+    [0x00, 0xcd, 0x00],
+    [0xcd, 0xcd, 0x00],
+    [0x00, 0x00, 0xee],
+    [0xcd, 0x00, 0xcd],
+    [0x00, 0xcd, 0xcd],
+    [0xe5, 0xe5, 0xe5],
+    [0x7f, 0x7f, 0x7f],
+    [0xff, 0x00, 0x00],
+    [0x00, 0xff, 0x00],
+    [0xff, 0xff, 0x00],
+    [0x5c, 0x5c, 0xff],
+    [0xff, 0x00, 0xff],
+    [0x00, 0xff, 0xff],
+    [0xff, 0xff, 0xff],
+];
+
+// "#rrggbb" (or "rrggbb"), and "none" for no background at all
+pub fn parse_color(value: &str) -> Option<Option<[u8; 3]>> {
+    let value = value.trim().to_lowercase();
+    if value == "none" {
+        return Some(None);
+    }
+
+    let hex = value.strip_prefix('#').unwrap_or(&value);
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Some([channel(0)?, channel(2)?, channel(4)?]))
+}
+
+// what a style is painted in. color 0 is the terminal's own foreground, which is dark on light backgrounds
+pub fn style_color(style: Style, conf: &Config) -> [u8; 3] {
+    if style.color == 0 {
+        let light = conf.background.is_some_and(|[r, g, b]| r as u32 * 3 + g as u32 * 6 + b as u32 > 1275);
+        return if light { PALETTE[0] } else { PALETTE[7] };
+    }
+
+    let color = (style.color as usize).min(15);
+    match color {
+        // the same pairs init makes, gray looks white
+        8 if conf.eight_colors => PALETTE[7],
+        9..=15 if conf.eight_colors => PALETTE[color - 8],
+        _ => PALETTE[color],
+    }
+}
+
+fn hex(color: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+// the rows and columns anything was drawn in, (top, left, bottom, right) with the bottom and right ones excluded,
+// and a cell of room all around
+pub fn bounds(grid: &TextRenderer) -> (i32, i32, i32, i32) {
+    let (rows, cols) = grid.size();
+    let (mut top, mut left, mut bottom, mut right) = (rows, cols, 0, 0);
+
+    for y in 0..rows {
+        for x in 0..cols {
+            if grid.cell(y, x).0 != ' ' {
+                top = top.min(y);
+                left = left.min(x);
+                bottom = bottom.max(y + 1);
+                right = right.max(x + 1);
+            }
+        }
+    }
+
+    // nothing at all is still a cell
+    if top >= bottom {
+        return (0, 0, 1, 1);
+    }
+    ((top - 1).max(0), (left - 1).max(0), (bottom + 1).min(rows), (right + 1).min(cols))
+}
+
+// --export-svg: every cell as monospace text in --font, --cell-size pixels apart, on --background.
+// runs of cells with the same style share one <text>, each glyph still gets its own x so they line up
+pub fn svg(grid: &TextRenderer, conf: &Config) -> String {
+    let (top, left, bottom, right) = bounds(grid);
+    let (cell_width, cell_height) = conf.cell_size.unwrap_or((10, 20));
+    let (width, height) = ((right - left) * cell_width, (bottom - top) * cell_height);
+    // most monospace fonts are about 0.6em wide
+    let font_size = (cell_width as f32 / 0.6).min(cell_height as f32 * 0.85);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        width, height, width, height
+    );
+    if let Some(background) = conf.background {
+        svg.push_str(&format!("<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n", hex(background)));
+    }
+    svg.push_str(&format!(
+        "<g font-family=\"{}\" font-size=\"{:.1}\" xml:space=\"preserve\">\n",
+        escape(&conf.font), font_size
+    ));
+
+    for y in top..bottom {
+        // the baseline, a bit up from the bottom of the cell for the descenders
+        let baseline = (y - top) as f32 * cell_height as f32 + cell_height as f32 * 0.78;
+        let mut x = left;
+
+        while x < right {
+            let (glyph, style) = grid.cell(y, x);
+            if glyph == ' ' {
+                x += 1;
+                continue;
+            }
+
+            let mut xs = Vec::new();
+            let mut text = String::new();
+            while x < right && grid.cell(y, x).0 != ' ' && grid.cell(y, x).1 == style {
+                xs.push(((x - left) * cell_width).to_string());
+                text.push(grid.cell(y, x).0);
+                x += 1;
+            }
+
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"{:.1}\" fill=\"{}\"{}>{}</text>\n",
+                xs.join(" "),
+                baseline,
+                hex(style_color(style, conf)),
+                if style.bold { " font-weight=\"bold\"" } else { "" },
+                escape(&text)
+            ));
+        }
+    }
+
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+// --export-png: every cell drawn with the built-in bitmap font, as many times its size as fits the cell
+// (twice by default) and centered in it. bold cells get the bold font, like a terminal would show them
+pub fn png(grid: &TextRenderer, conf: &Config) -> Result<Vec<u8>, png::EncodingError> {
+    let (top, left, bottom, right) = bounds(grid);
+    let (cell_width, cell_height) = conf.cell_size.unwrap_or((font::WIDTH * 2, font::HEIGHT * 2));
+    let scale = (cell_width / font::WIDTH).min(cell_height / font::HEIGHT).max(1);
+    let (offset_x, offset_y) = ((cell_width - font::WIDTH * scale) / 2, (cell_height - font::HEIGHT * scale) / 2);
+    let (width, height) = image_size(right - left, bottom - top, cell_width, cell_height)?;
+
+    let background = match conf.background {
+        Some([r, g, b]) => [r, g, b, 255],
+        None => [0, 0, 0, 0],
+    };
+    let mut pixels = Vec::new();
+    pixels.try_reserve_exact(width * height * 4).map_err(|_| png::EncodingError::LimitsExceeded)?;
+    pixels.extend(background.iter().cycle().take(width * height * 4));
+
+    for y in top..bottom {
+        for x in left..right {
+            let (glyph, style) = grid.cell(y, x);
+            if glyph == ' ' {
+                continue;
+            }
+
+            let [r, g, b] = style_color(style, conf);
+            let (cell_x, cell_y) = ((x - left) as usize * cell_width as usize, (y - top) as usize * cell_height as usize);
+
+            for (row, bits) in font::glyph(glyph, style.bold).iter().enumerate() {
+                for col in 0..font::WIDTH {
+                    if bits & (0x80 >> col) == 0 {
+                        continue;
+                    }
+
+                    // one font pixel is scale x scale image pixels, clipped to the cell. the glyph starts left of or
+                    // above its cell when the cell is smaller than the font
+                    for py in 0..scale {
+                        for px in 0..scale {
+                            let (in_x, in_y) = (offset_x + col * scale + px, offset_y + row as i32 * scale + py);
+                            if !(0..cell_width).contains(&in_x) || !(0..cell_height).contains(&in_y) {
+                                continue;
+                            }
+                            let (image_x, image_y) = (cell_x + in_x as usize, cell_y + in_y as usize);
+                            let i = (image_y * width + image_x) * 4;
+                            pixels[i..i + 4].copy_from_slice(&[r, g, b, 255]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut data = Vec::new();
+    let mut encoder = png::Encoder::new(&mut data, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+    Ok(data)
+}
+
+// (width, height) in pixels of cols x rows cells, an error when a side is longer than the 2^31 - 1 pixels a png can
+// have or there are too many bytes to count. png() finds out whether there is memory for them
+fn image_size(cols: i32, rows: i32, cell_width: i32, cell_height: i32) -> Result<(usize, usize), png::EncodingError> {
+    let side = |cells: i32, size: i32| (cells.max(0) as usize).checked_mul(size.max(0) as usize).filter(|&n| n <= i32::MAX as usize);
+    match (side(cols, cell_width), side(rows, cell_height)) {
+        (Some(width), Some(height)) if width.checked_mul(height).and_then(|n| n.checked_mul(4)).is_some() => Ok((width, height)),
+        _ => Err(png::EncodingError::LimitsExceeded),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn png_glyphs_stay_in_their_cell() {
+        let mut grid = TextRenderer::new(3, 3, true);
+        grid.put_cell(1, 1, '@');
+        let conf = Config { cell_size: Some((4, 6)), ..Config::default() };
+
+        let data = png(&grid, &conf).unwrap();
+        let mut reader = ::png::Decoder::new(data.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (12, 18));
+
+        let mut drawn = 0;
+        for (i, pixel) in pixels.chunks(4).enumerate() {
+            let (x, y) = (i % 12, i / 12);
+            if pixel != [0, 0, 0, 255] {
+                assert!((4..8).contains(&x) && (6..12).contains(&y), "pixel at {}, {} is outside the cell", x, y);
+                drawn += 1;
+            }
+        }
+        assert!(drawn > 0);
+    }
+
+    #[test]
+    fn png_too_large_is_an_error() {
+        assert_eq!(image_size(3, 3, 4, 6).ok(), Some((12, 18)));
+        assert!(image_size(i32::MAX, 1, 200, 1).is_err());
+        assert!(image_size(1, 20_000_000, 1, 200).is_err());
+    }
+
+    // the attributes and unescaped text of every <text>, checking on the way that the tags nest and that every &
+    // starts an entity, which is all the xml svg() writes needs to be well-formed
+    fn texts(svg: &str) -> Vec<(String, String)> {
+        let unescape = |s: &str| {
+            let mut rest = s;
+            let mut text = String::new();
+            while let Some(i) = rest.find('&') {
+                text.push_str(&rest[..i]);
+                let entities = [("&amp;", '&'), ("&lt;", '<'), ("&gt;", '>'), ("&quot;", '"')];
+                let (entity, c) = entities.into_iter().find(|(e, _)| rest[i..].starts_with(e)).expect("a bare &");
+                text.push(c);
+                rest = &rest[i + entity.len()..];
+            }
+            text + rest
+        };
+
+        let mut open = Vec::new();
+        let mut texts = Vec::new();
+        for (i, piece) in svg.split('<').enumerate() {
+            if i == 0 {
+                assert!(piece.is_empty());
+                continue;
+            }
+            let (tag, text) = piece.split_once('>').expect("a tag that never ends");
+            assert!(!text.contains('>'));
+            let text = unescape(text);
+
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(open.pop().as_deref(), Some(name));
+            } else if !tag.ends_with('/') {
+                let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+                unescape(attributes);
+                if name == "text" {
+                    texts.push((attributes.to_string(), text));
+                }
+                open.push(name.to_string());
+            }
+        }
+        assert!(open.is_empty());
+        texts
+    }
+
+    #[test]
+    fn svg_is_xml_with_every_cell() {
+        let mut grid = TextRenderer::new(24, 80, true);
+        grid.set_style(Style { color: 2, bold: true });
+        crate::render::put_str(&mut grid, 10, 30, "/|\\&&");
+        crate::render::draw_message(&mut grid, "<b>&\"c\"");
+        let conf = Config { font: String::from("Fira \"Mono\" & co"), ..Config::default() };
+
+        let svg = svg(&grid, &conf);
+        assert!(svg.contains(">&lt;b&gt;&amp;&quot;c&quot;</text>"));
+        assert!(svg.contains("font-family=\"Fira &quot;Mono&quot; &amp; co\""));
+
+        let texts = texts(&svg);
+        let (rows, cols) = grid.size();
+        let cells: Vec<_> = (0..rows).flat_map(|y| (0..cols).map(move |x| (y, x))).map(|(y, x)| grid.cell(y, x)).filter(|c| c.0 != ' ').collect();
+        assert_eq!(texts.iter().map(|(_, text)| text.chars().count()).sum::<usize>(), cells.len());
+
+        // every run is in the color of its cells, in order
+        let mut cells = cells.into_iter();
+        for (attributes, text) in &texts {
+            for glyph in text.chars() {
+                let (cell_glyph, style) = cells.next().unwrap();
+                assert_eq!(glyph, cell_glyph);
+                assert!(attributes.contains(&format!("fill=\"{}\"", hex(style_color(style, &conf)))));
+                assert_eq!(attributes.contains("font-weight=\"bold\""), style.bold);
+            }
+        }
+    }
+}
@@ -0,0 +1,676 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::Config;
+use crate::rules::{BranchState, CbonsaiRules, GrowthRules, Spawn};
+use crate::tree::{BranchNode, BranchType, Cell, Style, Tree};
+
+#[derive(Clone, Copy)]
+pub struct Counters {
+    pub branches: i32,
+    pub shoots: i32,
+    pub shoot_counter: i32
+}
+
+// what happens when a branch lands on a cell that is already taken
+#[derive(PartialEq, Clone, Copy)]
+pub enum CollisionPolicy {
+    // anything goes, same as cbonsai
+    Overwrite,
+    // taken cells are never drawn over
+    Skip,
+    // only leaves can be drawn over, the trunk and shoots stay readable
+    Leaves,
+    // like Leaves, and branches also turn aside instead of growing into wood
+    Steer,
+}
+
+pub const COLLISION_NAMES: &[(&str, CollisionPolicy)] = &[
+    ("overwrite", CollisionPolicy::Overwrite),
+    ("skip", CollisionPolicy::Skip),
+    ("leaves", CollisionPolicy::Leaves),
+    ("steer", CollisionPolicy::Steer),
+];
+
+impl CollisionPolicy {
+    pub fn from_name(name: &str) -> Option<CollisionPolicy> {
+        let name = name.trim().to_lowercase();
+        COLLISION_NAMES.iter().find(|(n, _)| *n == name).map(|(_, p)| *p)
+    }
+
+    fn allows(&self, taken: Option<&Cell>) -> bool {
+        match (self, taken) {
+            (_, None) | (CollisionPolicy::Overwrite, _) => true,
+            (CollisionPolicy::Skip, Some(_)) => false,
+            (CollisionPolicy::Leaves | CollisionPolicy::Steer, Some(cell)) => !cell.is_wood(),
+        }
+    }
+}
+
+// how hard a branch can be wired, in cells per step either way
+const MAX_WIRE: i32 = 2;
+
+// branches a tree may start per cell of its grid. far more than can be seen, cbonsai's own tables stay well under it
+// up to -L 80 or so, but rules where every branch spawns long lived trunks would otherwise never stop growing
+const BRANCHES_PER_CELL: i32 = 128;
+
+// one string placed into the tree, handed to whoever is watching the growth
+pub struct Step {
+    pub y: i32,
+    pub x: i32,
+    pub dx: i32,
+    pub dy: i32,
+    pub branch_type: BranchType,
+    pub len: i32,
+}
+
+// what the engine reports while it grows, in the order it happens
+#[derive(Clone, Copy)]
+pub enum GrowthEvent {
+    BranchStarted { branch_type: BranchType, y: i32, x: i32, life: i32 },
+    ShootSpawned { branch_type: BranchType, y: i32, x: i32, life: i32 },
+    BranchMoved { branch_type: BranchType, y: i32, x: i32, dx: i32, dy: i32 },
+    CellPlaced { y: i32, x: i32, glyph: char, branch_type: BranchType, style: Style },
+    GrowthFinished { branches: i32, shoots: i32 },
+}
+
+// anything that wants to follow the growth: renderers, overlays, stats, recorders...
+pub trait GrowthListener {
+    fn on_event(&mut self, event: &GrowthEvent);
+}
+
+// a branch that is still growing, what `branch` used to keep on its call stack
+struct Frame {
+    // the branch in the tree's branches, only kept with --prune
+    id: Option<usize>,
+    y: i32,
+    x: i32,
+    b_type: BranchType,
+    life: i32,
+    dx: i32,
+    dy: i32,
+    shoot_cooldown: i32,
+    // bent by hand while growing, added to every dx and dy it picks from then on
+    wire: (i32, i32),
+    // deltas are chosen (and any child branch spawned) before the frame places its own string
+    placing: bool,
+}
+
+impl Frame {
+    fn state(&self, conf: &Config, step: i32) -> BranchState {
+        BranchState {
+            branch_type: self.b_type,
+            y: self.y,
+            x: self.x,
+            life: self.life,
+            age: conf.life_start - self.life,
+            multiplier: conf.multiplier,
+            shoot_cooldown: self.shoot_cooldown,
+            step,
+            blossoms: conf.blossoms,
+            fruit: conf.fruit,
+        }
+    }
+}
+
+// grows a tree one step at a time, every call to next() places exactly one string.
+// the work stack replaces recursion, so growth can be paused and picked back up where it left off
+pub struct Growth<'a> {
+    conf: &'a Config,
+    rules: &'a dyn GrowthRules,
+    tree: Tree,
+    rng: StdRng,
+    counters: Counters,
+    // strings placed so far
+    steps: i32,
+    // once this many branches have started no more are spawned, what is growing finishes
+    branch_budget: i32,
+    stack: Vec<Frame>,
+    events: Vec<GrowthEvent>,
+    finished: bool,
+}
+
+impl<'a> Growth<'a> {
+    pub fn new(conf: &'a Config, height: i32, width: i32) -> Growth<'a> {
+        Growth::with_rules(conf, Tree::new(height, width), &CbonsaiRules)
+    }
+
+    // grows into the given (empty) tree, so callers can shape its ground first
+    pub fn with_rules(conf: &'a Config, tree: Tree, rules: &'a dyn GrowthRules) -> Growth<'a> {
+        Growth::seeded(conf, conf.seed as u64, tree, rules)
+    }
+
+    // like with_rules, for when one config grows several trees
+    pub fn seeded(conf: &'a Config, seed: u64, tree: Tree, rules: &'a dyn GrowthRules) -> Growth<'a> {
+        // every random decision of this tree comes from one generator, so a seed always regrows the same tree
+        let mut rng = StdRng::seed_from_u64(seed);
+        let shoot_counter = rng.gen_range(0..2);
+
+        let mut growth = Growth {
+            conf,
+            rules,
+            tree,
+            rng,
+            counters: Counters { branches: 0, shoots: 0, shoot_counter },
+            steps: 0,
+            branch_budget: 0,
+            stack: Vec::new(),
+            events: Vec::new(),
+            finished: false,
+        };
+
+        growth.branch_budget = growth.tree.width().saturating_mul(growth.tree.height()).saturating_mul(BRANCHES_PER_CELL).max(BRANCHES_PER_CELL);
+
+        // the trunk starts right on top of the ground in the middle
+        let x = growth.tree.width() / 2;
+        let y = growth.tree.ground(x) - 1;
+        growth.start_branch(None, y, x, BranchType::Trunk, conf.life_start);
+
+        // roots grow first, on top of the stack, spreading both ways from the foot of the trunk
+        if let Some(soil) = growth.tree.soil(x) {
+            let reach = conf.multiplier.max(1);
+            for i in 0..conf.roots {
+                let (b_type, root_x) = if i % 2 == 0 { (BranchType::RootLeft, x - 1) } else { (BranchType::RootRight, x + 1) };
+                let life = 2 + growth.rng.gen_range(0..=reach);
+                let trunk = growth.stack[0].id;
+                growth.start_branch(trunk, soil, root_x, b_type, life);
+            }
+        }
+
+        growth
+    }
+
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    pub fn counters(&self) -> &Counters {
+        &self.counters
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    pub fn into_tree(self) -> Tree {
+        self.tree
+    }
+
+    // events since the last drain, hand them to every listener in order
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, GrowthEvent> {
+        self.events.drain(..)
+    }
+
+    pub fn notify(&mut self, listeners: &mut [&mut dyn GrowthListener]) {
+        for event in self.events.drain(..) {
+            for listener in listeners.iter_mut() {
+                listener.on_event(&event);
+            }
+        }
+    }
+
+    // parent is the branch that spawned this one, None for the first trunk.
+    // the branch is only written down when it may be cut, a tree has far too many to keep them all
+    fn start_branch(&mut self, parent: Option<usize>, y: i32, x: i32, b_type: BranchType, life: i32) {
+        let id = self.conf.prune.then(|| self.tree.add_branch(BranchNode { parent, branch_type: b_type, y, x, life }));
+
+        self.counters.branches = self.counters.branches.saturating_add(1);
+        self.events.push(GrowthEvent::BranchStarted { branch_type: b_type, y, x, life });
+        self.stack.push(Frame {
+            id,
+            y,
+            x,
+            b_type,
+            life,
+            dx: 0,
+            dy: 0,
+            shoot_cooldown: self.conf.multiplier,
+            wire: (0, 0),
+            placing: false,
+        });
+    }
+
+    // wires the branch growing right now, it leans a cell further that way every step for the rest of its life
+    pub fn wire(&mut self, dx: i32, dy: i32) {
+        if let Some(frame) = self.stack.last_mut() {
+            frame.wire.0 = (frame.wire.0 + dx).clamp(-MAX_WIRE, MAX_WIRE);
+            frame.wire.1 = (frame.wire.1 + dy).clamp(-MAX_WIRE, MAX_WIRE);
+        }
+    }
+
+    // cuts the branch at (y, x) with everything growing off it, and buds a new one where it started with half its life.
+    // the new branch grows on the next calls to next()
+    pub fn prune(&mut self, y: i32, x: i32) -> bool {
+        let Some(cut) = self.tree.prune(y, x) else {
+            return false;
+        };
+
+        // anything still growing may have just been cut, the bud is all that grows now
+        self.stack.clear();
+        self.start_branch(cut.parent, cut.y, cut.x, cut.branch_type, (cut.life / 2).max(1));
+        self.finished = false;
+
+        true
+    }
+
+    // one turn of the old branch loop up to placing the string, returns the branch to grow first if one spawned
+    fn advance(&mut self) -> Option<(BranchType, i32)> {
+        let conf = self.conf;
+        let rng = &mut self.rng;
+        let my_counters = &mut self.counters;
+        let frame = self.stack.last_mut().expect("advance needs a growing branch");
+
+        frame.life -= 1;
+
+        (frame.dx, frame.dy) = self.rules.deltas(rng, &frame.state(conf, self.steps));
+        frame.dx += frame.wire.0;
+        frame.dy += frame.wire.1;
+
+        if frame.b_type.is_root() {
+            (frame.dx, frame.dy) = keep_in_soil(&self.tree, frame.y, frame.x, frame.dx, frame.dy);
+        } else {
+            if frame.dy > 0 && frame.y > (self.tree.ground(frame.x) - 2) { frame.dy -= 1; }
+            // a rules file or wiring can push down more than a row at a time, never into the ground
+            frame.dy = frame.dy.min(self.tree.ground(frame.x) - 1 - frame.y);
+
+            // and never off the top, left or right of the window
+            frame.dy = frame.dy.max(-frame.y);
+            frame.dx = (frame.x + frame.dx).clamp(0, (self.tree.width() - 1).max(0)) - frame.x;
+
+            // don't walk sideways into ground that is higher than we are (only happens next to the pot)
+            if frame.y + frame.dy >= self.tree.ground(frame.x + frame.dx) { frame.dx = 0; }
+        }
+
+        let state = frame.state(conf, self.steps);
+        let mut child = None;
+        if let Some(b_type) = self.rules.decay(&state) {
+            child = Some((b_type, frame.life));
+        } else if my_counters.branches < self.branch_budget {
+            match self.rules.spawn(rng, &state) {
+                Spawn::Trunk { life } => {
+                    frame.shoot_cooldown = self.rules.shoot_cooldown(conf.multiplier);
+                    child = Some((BranchType::Trunk, life));
+                },
+                Spawn::Shoot { life } => {
+                    frame.shoot_cooldown = self.rules.shoot_cooldown(conf.multiplier);
+
+                    my_counters.shoots = my_counters.shoots.saturating_add(1);
+                    // only whether it's odd or even matters, and that way it never overflows
+                    my_counters.shoot_counter = (my_counters.shoot_counter + 1) % 2;
+
+                    let shoot_type = BranchType::from_i32((my_counters.shoot_counter % 2) + 1);
+                    self.events.push(GrowthEvent::ShootSpawned { branch_type: shoot_type, y: frame.y, x: frame.x, life });
+
+                    child = Some((shoot_type, life));
+                },
+                Spawn::Blossom { life } => child = Some((BranchType::Blossom, life)),
+                Spawn::Fruit { life } => child = Some((BranchType::Fruit, life)),
+                Spawn::Nothing => (),
+            }
+        }
+        frame.shoot_cooldown -= 1;
+        frame.placing = true;
+
+        child
+    }
+
+    fn place(&mut self) -> Step {
+        let conf = self.conf;
+        let frame = self.stack.last_mut().expect("place needs a growing branch");
+        frame.placing = false;
+        self.steps = self.steps.saturating_add(1);
+
+        // steered only now, the branches it spawned since it picked its move may have grown into the way
+        if conf.collisions == CollisionPolicy::Steer && !frame.b_type.is_root() {
+            frame.dx = steer(&self.tree, frame.y, frame.x, frame.dx, frame.dy);
+        }
+
+        frame.x += frame.dx;
+        frame.y += frame.dy;
+        self.events.push(GrowthEvent::BranchMoved { branch_type: frame.b_type, y: frame.y, x: frame.x, dx: frame.dx, dy: frame.dy });
+
+        let state = frame.state(conf, self.steps);
+        let style: Style = self.rules.style(&mut self.rng, frame.b_type);
+
+        let mut branch_str: String = self.rules.glyphs(&mut self.rng, &state, frame.dx, frame.dy, &conf.leaves[..conf.leaves_size as usize]);
+
+        // long strings (leaves are 32 wide) stop at the right edge
+        let room = (self.tree.width() - frame.x).max(0) as usize;
+        if let Some((cut, _)) = branch_str.char_indices().nth(room) {
+            branch_str.truncate(cut);
+        }
+
+        // i do not think i need to do anything with wide characters,
+        // i think rust handles unicode stuff better by default than C
+        //
+        // i could be (probably am) wrong but thats a problem for another time
+
+        // the same test choose_string uses to draw leaves instead of wood
+        let leaf = frame.b_type.draws_leaves(frame.life);
+
+        for (i, glyph) in branch_str.chars().enumerate() {
+            let x = frame.x + i as i32;
+            if !self.tree.contains(frame.y, x) || !conf.collisions.allows(self.tree.get(frame.y, x)) {
+                continue;
+            }
+
+            // blossoms and fruit grow before the leaves around them are drawn, leaves never cover them
+            let flower = self.tree.get(frame.y, x).is_some_and(|c| matches!(c.branch_type, BranchType::Blossom | BranchType::Fruit));
+            if leaf && flower {
+                continue;
+            }
+
+            self.tree.set(frame.y, x, Some(Cell { glyph, branch_type: frame.b_type, style, leaf, branch: frame.id }));
+            self.events.push(GrowthEvent::CellPlaced { y: frame.y, x, glyph, branch_type: frame.b_type, style });
+        }
+
+        Step { y: frame.y, x: frame.x, dx: frame.dx, dy: frame.dy, branch_type: frame.b_type, len: branch_str.chars().count() as i32 }
+    }
+}
+
+// roots creep along the top of the soil and at most one row down into it, never out of the pot
+fn keep_in_soil(tree: &Tree, y: i32, x: i32, dx: i32, dy: i32) -> (i32, i32) {
+    let in_soil = |dx: i32, dy: i32| {
+        let (ty, tx) = (y + dy, x + dx);
+        tree.contains(ty, tx) && tree.soil(tx).is_some_and(|top| ty >= top && ty <= top + 1)
+    };
+
+    [(dx, dy), (dx, 0), (0, dy)].into_iter().find(|&(dx, dy)| in_soil(dx, dy)).unwrap_or((0, 0))
+}
+
+// the closest dx (trying one cell either side, then two) that doesn't grow into wood or ground
+fn steer(tree: &Tree, y: i32, x: i32, dx: i32, dy: i32) -> i32 {
+    let free = |dx: i32| {
+        let (ty, tx) = (y + dy, x + dx);
+        // staying put never collides with anything but ourselves
+        (dx == 0 && dy == 0) || (tree.contains(ty, tx) && ty < tree.ground(tx) && !tree.get(ty, tx).is_some_and(|c| c.is_wood()))
+    };
+
+    [dx, dx + 1, dx - 1, dx + 2, dx - 2].into_iter().find(|&dx| free(dx)).unwrap_or(dx)
+}
+
+impl Iterator for Growth<'_> {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Step> {
+        loop {
+            let Some(frame) = self.stack.last() else {
+                if !self.finished {
+                    self.finished = true;
+                    self.events.push(GrowthEvent::GrowthFinished { branches: self.counters.branches, shoots: self.counters.shoots });
+                }
+                return None;
+            };
+
+            if frame.placing {
+                return Some(self.place());
+            }
+
+            if frame.life <= 0 {
+                self.stack.pop();
+                continue;
+            }
+
+            // a spawned branch grows to the end before its parent places this turn's string.
+            // no branch outlives a shoot of the first trunk, or rules could hand out ever longer lives
+            let (id, y, x) = (frame.id, frame.y, frame.x);
+            if let Some((b_type, life)) = self.advance() {
+                self.start_branch(id, y, x, b_type, life.min(self.conf.life_start + self.conf.multiplier));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conf(life_start: i32, prune: bool) -> Config {
+        let mut conf = Config { life_start, prune, seed: 7, ..Config::default() };
+        crate::args::default_leaves(&mut conf);
+        conf
+    }
+
+    // every cell of the tree as text, a row per line
+    fn glyphs(tree: &Tree) -> String {
+        let mut text = String::new();
+        for y in 0..tree.height() {
+            text.extend((0..tree.width()).map(|x| tree.get(y, x).map_or(' ', |c| c.glyph)));
+            text.push('\n');
+        }
+        text
+    }
+
+    fn grow(conf: &Config, seed: u64) -> Growth<'_> {
+        let mut growth = Growth::seeded(conf, seed, Tree::new(30, 80), &CbonsaiRules);
+        growth.by_ref().for_each(drop);
+        growth
+    }
+
+    #[test]
+    fn a_seed_regrows_the_same_tree() {
+        let conf = conf(32, false);
+        let (first, second) = (grow(&conf, 3), grow(&conf, 3));
+        assert_eq!(glyphs(first.tree()), glyphs(second.tree()));
+        assert_eq!(first.counters().branches, second.counters().branches);
+        assert_eq!(first.counters().shoots, second.counters().shoots);
+
+        assert_ne!(glyphs(first.tree()), glyphs(grow(&conf, 4).tree()));
+    }
+
+    #[test]
+    fn branches_are_only_kept_to_prune() {
+        let conf = conf(72, false);
+        let mut growth = Growth::new(&conf, 60, 200);
+        growth.by_ref().for_each(drop);
+        assert!(growth.counters().branches > 1000);
+        assert!(growth.tree().branch(0).is_none());
+
+        let conf = self::conf(32, true);
+        let mut growth = Growth::new(&conf, 30, 80);
+        growth.by_ref().for_each(drop);
+        assert!(growth.tree().branch(growth.counters().branches as usize - 1).is_some());
+    }
+
+    #[test]
+    fn growth_picks_up_where_it_was_paused() {
+        let conf = conf(32, false);
+        let whole = grow(&conf, 5);
+
+        let mut growth = Growth::seeded(&conf, 5, Tree::new(30, 80), &CbonsaiRules);
+        assert_eq!(growth.by_ref().take(40).count(), 40);
+        assert!(!growth.is_finished());
+        let paused = glyphs(growth.tree());
+        growth.by_ref().for_each(drop);
+
+        assert_ne!(paused, glyphs(growth.tree()));
+        assert_eq!(glyphs(whole.tree()), glyphs(growth.tree()));
+        assert_eq!(whole.counters().branches, growth.counters().branches);
+    }
+
+    #[test]
+    fn growth_ends() {
+        let conf = conf(32, false);
+        let mut growth = Growth::seeded(&conf, 6, Tree::new(30, 80), &CbonsaiRules);
+        let steps = growth.by_ref().count();
+
+        assert!(steps > 0);
+        assert!(growth.is_finished());
+        assert!(growth.next().is_none());
+    }
+
+    // keeps every event it's handed
+    struct Recorder(Vec<GrowthEvent>);
+
+    impl GrowthListener for Recorder {
+        fn on_event(&mut self, event: &GrowthEvent) {
+            self.0.push(*event);
+        }
+    }
+
+    #[test]
+    fn events_replay_the_tree() {
+        let conf = conf(32, false);
+        let mut growth = Growth::seeded(&conf, 8, Tree::new(30, 80), &CbonsaiRules);
+        let mut recorder = Recorder(Vec::new());
+        while growth.next().is_some() {
+            growth.notify(&mut [&mut recorder]);
+        }
+        // once more after the end changes nothing
+        growth.next();
+        growth.notify(&mut [&mut recorder]);
+
+        let mut replay = Tree::new(30, 80);
+        for event in &recorder.0 {
+            if let GrowthEvent::CellPlaced { y, x, glyph, branch_type, style } = *event {
+                replay.set(y, x, Some(Cell { glyph, branch_type, style, leaf: false, branch: None }));
+            }
+        }
+        assert_eq!(glyphs(&replay), glyphs(growth.tree()));
+
+        let finished: Vec<_> = recorder.0.iter().filter(|e| matches!(e, GrowthEvent::GrowthFinished { .. })).collect();
+        assert_eq!(finished.len(), 1);
+        assert!(matches!(recorder.0.last(), Some(GrowthEvent::GrowthFinished { branches, .. }) if *branches == growth.counters().branches));
+    }
+
+    #[test]
+    fn growth_stays_inside_a_tiny_grid() {
+        let conf = conf(32, false);
+        for seed in 0..20 {
+            let mut growth = Growth::seeded(&conf, seed, Tree::new(3, 5), &CbonsaiRules);
+            while let Some(step) = growth.next() {
+                assert!(growth.tree().contains(step.y, step.x), "seed {} grew to {}, {}", seed, step.y, step.x);
+                for event in growth.drain_events() {
+                    if let GrowthEvent::CellPlaced { y, x, .. } = event {
+                        assert!((0..3).contains(&y) && (0..5).contains(&x), "seed {} placed a cell at {}, {}", seed, y, x);
+                    }
+                }
+            }
+            assert!(glyphs(growth.tree()).chars().any(|c| c != ' ' && c != '\n'));
+        }
+    }
+
+    // every wood cell drawn over while conf grows seed, as (y, x)
+    fn wood_drawn_over(conf: &Config, seed: u64) -> Vec<(i32, i32)> {
+        let mut growth = Growth::seeded(conf, seed, Tree::new(30, 80), &CbonsaiRules);
+        let mut before = growth.tree().clone();
+        let mut drawn_over = Vec::new();
+
+        while growth.next().is_some() {
+            for y in 0..30 {
+                for x in 0..80 {
+                    let (Some(old), Some(new)) = (before.get(y, x), growth.tree().get(y, x)) else { continue };
+                    if old.is_wood() && (old.glyph, old.branch_type, old.leaf) != (new.glyph, new.branch_type, new.leaf) {
+                        drawn_over.push((y, x));
+                    }
+                }
+            }
+            before = growth.tree().clone();
+        }
+        drawn_over
+    }
+
+    #[test]
+    fn wood_stays_unless_anything_goes() {
+        let mut conf = conf(32, false);
+        assert!(!wood_drawn_over(&conf, 4).is_empty());
+
+        for collisions in [CollisionPolicy::Skip, CollisionPolicy::Leaves] {
+            conf.collisions = collisions;
+            assert_eq!(wood_drawn_over(&conf, 4), []);
+        }
+    }
+
+    #[test]
+    fn skip_never_draws_over_anything() {
+        let conf = Config { collisions: CollisionPolicy::Skip, ..conf(32, false) };
+        let mut growth = Growth::seeded(&conf, 4, Tree::new(30, 80), &CbonsaiRules);
+        let mut before = growth.tree().clone();
+
+        while growth.next().is_some() {
+            for event in growth.drain_events() {
+                if let GrowthEvent::CellPlaced { y, x, .. } = event {
+                    assert!(before.get(y, x).is_none(), "drew over ({}, {})", y, x);
+                }
+            }
+            before = growth.tree().clone();
+        }
+    }
+
+    #[test]
+    fn steer_keeps_branches_off_wood() {
+        // how many times a branch moves onto wood that was there before it moved, though a cell steer tries was free
+        let moves_onto_wood = |collisions: CollisionPolicy| {
+            let conf = Config { collisions, ..conf(32, false) };
+            let mut growth = Growth::seeded(&conf, 4, Tree::new(30, 80), &CbonsaiRules);
+            let mut before = growth.tree().clone();
+            let mut moves = 0;
+
+            while let Some(step) = growth.next() {
+                let moved = step.dx != 0 || step.dy != 0;
+                let wood = |x: i32| !before.contains(step.y, x) || before.get(step.y, x).is_some_and(|c| c.is_wood());
+                let boxed_in = (step.x - 2..=step.x + 2).all(wood);
+                if moved && wood(step.x) && !boxed_in {
+
+                    moves += 1;
+                }
+                before = growth.tree().clone();
+            }
+            moves
+        };
+
+        assert!(moves_onto_wood(CollisionPolicy::Leaves) > 0);
+        assert_eq!(moves_onto_wood(CollisionPolicy::Steer), 0);
+    }
+
+    #[test]
+    fn roots_stay_in_the_soil() {
+        let conf = Config { roots: 8, ..conf(32, false) };
+        let pots = crate::scene::pot_rects(&conf, 24, &[(0, 80)]);
+
+        // next to the pot of a cascade the ground is further down than the soil
+        for (seed, hangs_below_rim) in (0..10).flat_map(|seed| [(seed, false), (seed, true)]) {
+            let pot = crate::scene::plant(24, (0, 80), pots[0], 24, hangs_below_rim);
+            let mut growth = Growth::seeded(&conf, seed, pot.clone(), &CbonsaiRules);
+            let mut roots = 0;
+
+            while growth.next().is_some() {
+                for event in growth.drain_events() {
+                    if let GrowthEvent::CellPlaced { y, x, branch_type, .. } = event {
+                        if branch_type.is_root() {
+                            let soil = pot.soil(x).expect("a root outside the pot");
+                            assert!(y >= pot.ground(x) && (soil..=soil + 1).contains(&y), "seed {}: a root at ({}, {})", seed, y, x);
+                            roots += 1;
+                        }
+                    }
+                }
+            }
+            assert!(roots > 0, "seed {} grew no roots", seed);
+        }
+    }
+
+    #[test]
+    fn wiring_bends_only_the_growing_branch() {
+        let conf = conf(32, false);
+        let mut wired = Growth::seeded(&conf, 6, Tree::new(30, 80), &CbonsaiRules);
+        let mut twin = Growth::seeded(&conf, 6, Tree::new(30, 80), &CbonsaiRules);
+        for _ in 0..40 {
+            assert_eq!(wired.next().map(|s| (s.y, s.x)), twin.next().map(|s| (s.y, s.x)));
+        }
+
+        let depth = wired.stack.len();
+        wired.wire(2, 0);
+        assert_eq!(wired.stack.last().map(|f| f.wire), Some((2, 0)));
+        assert!(wired.stack[..depth - 1].iter().all(|f| f.wire == (0, 0)));
+
+        // the branches it spawns in the meantime grow as they would have, then it leans right
+        loop {
+            let (step, twin_step) = (wired.next().unwrap(), twin.next().unwrap());
+            if (step.dx, step.dy) != (twin_step.dx, twin_step.dy) {
+                assert_eq!(wired.stack.len(), depth);
+                assert!(step.dx > twin_step.dx);
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+pub mod args;
+pub mod export;
+pub mod font;
+pub mod growth;
+pub mod render;
+pub mod rules;
+pub mod rules_file;
+pub mod scene;
+pub mod seasons;
+pub mod species;
+pub mod styles;
+pub mod tree;
+pub mod weather;
+pub mod wind;
+
+use std::env;
+
+use growth::{CollisionPolicy, Counters};
+use render::{Backend, TextRenderer};
+use rules::{CbonsaiRules, GrowthRules};
+use rules_file::{RulesError, TableRules};
+use species::{Species, SpeciesRules};
+use styles::BonsaiStyle;
+use weather::Weather;
+use wind::WindRules;
+
+#[derive(Clone)]
+pub struct Config {
+    pub live: bool,
+    pub infinite: i32,
+    pub screensaver: bool,
+    pub print_tree: i32,
+    pub verbosity: i32,
+    pub life_start: i32,
+    pub multiplier: i32,
+    pub base_type: i32,
+    pub seed: i32,
+    // no --seed was given, so the seed was picked at random and is reported once the tree is done
+    pub seed_picked: bool,
+    pub leaves_size: i32,
+    pub save: bool,
+    pub load: bool,
+    pub target_branch_count: i32,
+
+    pub time_wait: f32,
+    pub time_step: f32,
+
+    pub message: String,
+    pub leaves: [char; 64],
+    pub save_file: String,
+    pub load_file: String,
+    pub rules_file: String,
+    pub style: Option<BonsaiStyle>,
+    pub species: Option<Species>,
+    pub wind: f32,
+    pub lean: f32,
+    pub gusts: bool,
+    pub collisions: CollisionPolicy,
+    pub auto_scale: bool,
+    pub trees: i32,
+    pub blossoms: f32,
+    pub fruit: f32,
+    pub roots: i32,
+    // seconds a year takes with --seasons, 0 is off. season is how far into the year we are (0.0 to 1.0)
+    pub seasons: f32,
+    pub season: f32,
+
+    // --persist: the tree keeps growing between runs, growth_rate branches per hour up to max_branches (0 is no cap).
+    // born and saved are unix times from the save file, saved being as far as growth has been counted.
+    // branch_limit is where this run stops growing
+    pub persist: bool,
+    pub growth_rate: f32,
+    pub max_branches: i32,
+    pub born: i64,
+    pub saved: i64,
+    pub branch_limit: i32,
+
+    // cut branches off the finished tree before it's done
+    pub prune: bool,
+    pub weather: Option<Weather>,
+    pub backend: Backend,
+
+    // --headless: no terminal at all, the tree grows in a width x height grid and comes out as text.
+    // the size is used by --backend=ansi and plain too, 0 is the terminal's size there and 80x24 headless
+    pub headless: bool,
+    pub width: i32,
+    pub height: i32,
+
+    // --export-svg and --export-png, empty for none. font, cell size (width, height in pixels, None is each
+    // format's own) and background (None is transparent) are how the exported cells look
+    pub export_svg: String,
+    pub export_png: String,
+    pub font: String,
+    pub cell_size: Option<(i32, i32)>,
+    pub background: Option<[u8; 3]>,
+    // ncurses drew the bright colors as the normal ones (terminals without 256 colors), exports do the same
+    pub eight_colors: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            live: false,
+            infinite: 0,
+            screensaver: false,
+            print_tree: 0,
+            verbosity: 0,
+            life_start: 32,
+            multiplier: 5,
+            base_type: 1,
+            seed: 0,
+            seed_picked: false,
+            leaves_size: 0,
+            save: false,
+            load: false,
+            target_branch_count: 0,
+
+            time_wait: 4.0,
+            time_step: 0.03,
+
+            message: String::new(),
+            leaves: ['\0'; 64],
+            save_file: default_cache_path(),
+            load_file: default_cache_path(),
+            rules_file: String::new(),
+            style: None,
+            species: None,
+            wind: 0.0,
+            lean: 0.0,
+            gusts: false,
+            collisions: CollisionPolicy::Overwrite,
+            auto_scale: false,
+            trees: 1,
+            blossoms: 0.0,
+            fruit: 0.0,
+            roots: 0,
+            seasons: 0.0,
+            season: 0.0,
+
+            persist: false,
+            growth_rate: 2.0,
+            max_branches: 0,
+            born: 0,
+            saved: 0,
+            branch_limit: 0,
+
+            prune: false,
+            weather: None,
+            backend: Backend::Ncurses,
+
+            headless: false,
+            width: 0,
+            height: 0,
+
+            export_svg: String::new(),
+            export_png: String::new(),
+            font: String::from("monospace"),
+            cell_size: None,
+            background: Some([0, 0, 0]),
+            eight_colors: false,
+        }
+    }
+}
+
+// $XDG_CACHE_HOME/rbonsai, falling back to $HOME/.cache/rbonsai
+pub fn default_cache_path() -> String {
+    match env::var("XDG_CACHE_HOME") {
+        Ok(dir) if !dir.is_empty() => format!("{}/rbonsai", dir),
+        _ => match env::var("HOME") {
+            Ok(home) => format!("{}/.cache/rbonsai", home),
+            Err(_) => String::from("rbonsai"),
+        },
+    }
+}
+
+
+// the tables the tree grows by: a style, a rules file or the species' own. None is plain cbonsai
+pub fn table_rules(conf: &Config) -> Result<Option<TableRules>, RulesError> {
+    if let Some(style) = conf.style {
+        Ok(Some(style.rules()))
+    } else if !conf.rules_file.is_empty() {
+        TableRules::load(&conf.rules_file).map(Some)
+    } else {
+        Ok(conf.species.map(|s| s.rules()))
+    }
+}
+
+// hands the rules conf grows by to grow: the table rules, or cbonsai's without any, drawn like the species if
+// there is one and blown by the wind if there is some. a rules file that doesn't load is the error, grow isn't
+// called then
+pub fn with_rules<T>(conf: &Config, grow: impl FnOnce(&dyn GrowthRules) -> T) -> Result<T, RulesError> {
+    let table_rules = table_rules(conf)?;
+    let growth_rules: &dyn GrowthRules = match &table_rules {
+        Some(table_rules) => table_rules,
+        None => &CbonsaiRules,
+    };
+
+    // a species keeps its looks whatever shaped the growth
+    let species_rules = conf.species.map(|s| SpeciesRules { growth: growth_rules, look: s.look() });
+    let rules: &dyn GrowthRules = match &species_rules {
+        Some(species_rules) => species_rules,
+        None => growth_rules,
+    };
+
+    let wind_rules = (conf.wind != 0.0 || conf.lean != 0.0).then(|| WindRules::new(rules, conf.wind, conf.lean, conf.gusts));
+    let rules: &dyn GrowthRules = match &wind_rules {
+        Some(wind_rules) => wind_rules,
+        None => rules,
+    };
+
+    Ok(grow(rules))
+}
+
+// grows the whole tree without a terminal on a conf.height x conf.width grid (80x24 where they're 0) and returns
+// what the screen would show. its text() has color escapes if conf.print_tree is set. the seed is used as it is
+pub fn grow_headless(conf: &Config) -> Result<(TextRenderer, Counters), RulesError> {
+    with_rules(conf, |rules| grow_headless_by(conf, rules))
+}
+
+// grow_headless with rules already picked
+pub fn grow_headless_by(conf: &Config, rules: &dyn GrowthRules) -> (TextRenderer, Counters) {
+    let rows = if conf.height > 0 { conf.height } else { 24 };
+    let cols = if conf.width > 0 { conf.width } else { 80 };
+    let mut text = TextRenderer::new(rows, cols, conf.print_tree != 0);
+
+    // a Config that didn't come from parse_args may have no leaves yet, or a multiplier parse_args would reject
+    let mut conf = conf.clone();
+    args::default_leaves(&mut conf);
+    conf.multiplier = conf.multiplier.max(1);
+    if conf.auto_scale {
+        scene::fit_to_grid(&mut conf, rows, cols);
+    }
+    let my_counters = scene::grow_in_memory(&conf, rules, &mut text, false);
+
+    (text, my_counters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grow_headless_regrows_a_seed() {
+        let conf = Config { seed: 42, message: String::from("a bonsai"), ..Config::default() };
+        let (first, first_counters) = grow_headless(&conf).unwrap();
+        let (second, second_counters) = grow_headless(&conf).unwrap();
+
+        assert!(first.text().contains('&'));
+        assert_eq!(first.text(), second.text());
+        assert_eq!((first_counters.branches, first_counters.shoots), (second_counters.branches, second_counters.shoots));
+    }
+}
@@ -1,150 +1,207 @@
 #![allow(dead_code)]
 
-use ncurses::*;
 use rand::{thread_rng, Rng};
-use core::panic;
 use std::process::exit;
 use std::io::prelude::*;
 use std::path::Path;
-use std::fs::File;
-use std::thread;
-use std::time::Duration;
-use std::fmt;
-
-#[derive(PartialEq, Clone, Copy)]
-enum BranchType {
-    Trunk,
-    ShootLeft,
-    ShootRight,
-    Dying,
-    Dead
+use std::fs::{self, File};
+use std::str::FromStr;
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rbonsai::{args, export, render, scene, Config};
+use rbonsai::args::Command;
+use rbonsai::growth::Counters;
+use rbonsai::render::{AnsiRenderer, Backend, Tee, TextRenderer};
+use rbonsai::rules::GrowthRules;
+
+#[cfg(feature = "ncurses")]
+mod curses;
+
+fn now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64)
 }
 
-impl BranchType {
-    fn from_i32(value: i32) -> BranchType {
-        match value {
-            0 => BranchType::Trunk,
-            1 => BranchType::ShootLeft,
-            2 => BranchType::ShootRight,
-            3 => BranchType::Dying,
-            4 => BranchType::Dead,
-            _ => panic!("invalid branch i32 conversion"),
-        }
-    }
+// a save file or export that can't be written or read back ends the run with a message instead of a panic
+fn file_error(fname: &str, reason: impl std::fmt::Display) -> ! {
+    eprintln!("error: {}: {}", fname, reason);
+    exit(1);
 }
 
-impl fmt::Display for BranchType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self {
-            BranchType::Trunk => write!(f, "Trunk"),
-            BranchType::ShootLeft => write!(f, "ShootLeft"),
-            BranchType::ShootRight => write!(f, "ShootRight"),
-            BranchType::Dying => write!(f, "Dying"),
-            BranchType::Dead => write!(f, "Dead"),
-        }
+fn parse_field<T: FromStr>(fname: &str, name: &str, value: Option<&str>) -> T {
+    match value {
+        Some(value) => value.parse().unwrap_or_else(|_| file_error(fname, format!("bad {} '{}'", name, value))),
+        None => file_error(fname, format!("no {}", name)),
     }
 }
 
-struct Config {
-    live: bool,
-    infinite: i32,
-    screensaver: bool,
-    print_tree: i32,
-    verbosity: i32,
-    life_start: i32,
-    multiplier: i32,
-    base_type: i32,
-    seed: i32,
-    leaves_size: i32,
-    save: bool,
-    load: bool,
-    target_branch_count: i32,
-
-    time_wait: f32,
-    time_step: f32,
-
-    message: String,
-    leaves: [char; 64],
-    save_file: String,
-    load_file: String
-}
-
-struct NcursesObjects {
-    base_win: Option<WINDOW>,
-    tree_win: Option<WINDOW>,
-    message_border_win: Option<WINDOW>,
-    message_win: Option<WINDOW>,
-
-    base_panel: Option<PANEL>,
-    tree_panel: Option<PANEL>,
-    message_border_panel: Option<PANEL>,
-    message_panel: Option<PANEL>
-}
-
-struct Counters {
-    branches: i32,
-    shoots: i32,
-    shoot_counter: i32
-}
+// "seed branch_count" and then whatever is on: the season, and for --persist when the tree was born and last saved.
+// e.g. "42 80 season=0.25 born=1760659200 saved=1760745600"
+fn save_to_file(conf: &Config, branch_count: i32) {
+    let fname = conf.save_file.as_str();
+    let path = Path::new(fname);
 
-#[allow(unused_variables)]
-fn quit(conf: &Config, objects: &NcursesObjects, return_code: i32) {
-    del_panel(objects.base_panel.expect("could not get base_panel"));
-    del_panel(objects.tree_panel.expect("could not get tree_panel"));
-    del_panel(objects.message_border_panel.expect("could not get message_border_panel"));
-    del_panel(objects.message_panel.expect("could not get message_panel"));
+    // the default is in ~/.cache, which may not be there yet
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        if let Err(e) = fs::create_dir_all(dir) {
+            file_error(fname, format!("couldn't create its directory: {}", e));
+        }
+    }
 
-    delwin(objects.base_win.expect("could not get base_win"));
-    delwin(objects.tree_win.expect("could not get tree_win"));
-    delwin(objects.message_border_win.expect("could not get message_border_win"));
-    delwin(objects.message_win.expect("could not get message_win"));
+    let mut file = match File::create(path) {
+        Err(e) => file_error(fname, format!("couldn't create save file: {}", e)),
+        Ok(file) => file,
+    };
 
-    // free conf.save_file and conf.load_file
+    let mut data = format!("{} {}", &conf.seed.to_string(), &branch_count.to_string());
+    if conf.seasons > 0.0 {
+        data.push_str(&format!(" season={}", conf.season));
+    }
+    if conf.persist {
+        data.push_str(&format!(" born={} saved={}", conf.born, conf.saved));
+    }
 
-    exit(return_code)
+    // on stderr, stdout is for the tree -p and --headless print
+    match file.write_all(data.as_bytes()) {
+        Err(e) => file_error(fname, format!("couldn't write to save file: {}", e)),
+        Ok(_) => eprintln!("wrote to save file"),
+    }
 }
 
-fn save_to_file(fname: &str, seed: i32, branch_count: i32) {
-    let path = Path::new(fname);
-
-    let mut file = match File::create(&path) {
-        Err(e) => panic!("couldn't create save file: {}", e),
+// --export-svg and --export-png, overwriting whatever was there
+fn export_to_file(fname: &str, data: &[u8]) {
+    let mut file = match File::create(Path::new(fname)) {
+        Err(e) => file_error(fname, format!("couldn't create export: {}", e)),
         Ok(file) => file,
     };
 
-    match file.write_all(format!("{} {}", &seed.to_string(), &branch_count.to_string()).as_bytes()) {
-        Err(e) => panic!("couldn't write to save file: {}", e),
-        Ok(_) => println!("wrote to save file"),
+    if let Err(e) = file.write_all(data) {
+        file_error(fname, format!("couldn't write export: {}", e));
     }
 }
 
 fn load_from_file(conf: &mut Config) {
     let path = Path::new(conf.load_file.as_str());
 
+    // nothing has been saved yet, start from scratch
     let mut file = match File::open(path) {
-        Err(e) => panic!("couldn't open load file: {}", e),
+        Err(e) => {
+            eprintln!("couldn't open load file: {}", e);
+            return;
+        },
         Ok(file) => file,
     };
 
+    let fname = conf.load_file.clone();
     let mut load_data = String::new();
     match file.read_to_string(&mut load_data) {
-        Err(e) => panic!("couldn't read load file to string: {}", e),
-        Ok(_) => println!("read from load file"),
+        Err(e) => file_error(&fname, format!("couldn't read load file to string: {}", e)),
+        Ok(_) => eprintln!("read from load file"),
     }
 
-    let load_data: Vec<i32> = load_data.split_ascii_whitespace().map(|s| s.parse().unwrap()).collect();
-    conf.seed = load_data[0];
-    conf.target_branch_count = load_data[1];
+    let mut load_data = load_data.split_ascii_whitespace();
+    conf.seed = parse_field(&fname, "seed", load_data.next());
+    conf.target_branch_count = parse_field(&fname, "branch count", load_data.next());
+
+    for field in load_data {
+        match field.split_once('=') {
+            Some(("season", v)) => conf.season = parse_field(&fname, "season", Some(v)),
+            Some(("born", v)) => conf.born = parse_field(&fname, "born", Some(v)),
+            Some(("saved", v)) => conf.saved = parse_field(&fname, "saved", Some(v)),
+            // a bare third number is the season, from before fields had names
+            None => conf.season = parse_field(&fname, "season", Some(field)),
+            _ => (),
+        }
+    }
 }
 
-fn finish(conf: &Config, my_counters: &Counters) {
-    clear();
-    refresh();
-    endwin();
-
+// the save, exports and -v output, once the tree is done whatever it was drawn with
+fn wrap_up(conf: &Config, my_counters: &Counters, screen: Option<&TextRenderer>) {
+    // a living tree saves how far it has grown, even where its life ran out first, so it keeps aging
     if conf.save {
-        save_to_file(&conf.save_file, conf.seed, my_counters.branches);
+        save_to_file(conf, if conf.persist { conf.branch_limit.max(my_counters.branches) } else { my_counters.branches });
+    }
+
+    if let Some(screen) = screen.filter(|_| !conf.export_svg.is_empty()) {
+        export_to_file(&conf.export_svg, export::svg(screen, conf).as_bytes());
+    }
+    if let Some(screen) = screen.filter(|_| !conf.export_png.is_empty()) {
+        match export::png(screen, conf) {
+            Ok(data) => export_to_file(&conf.export_png, &data),
+            Err(e) => file_error(&conf.export_png, format!("couldn't encode png: {}", e)),
+        }
+    }
+
+    // -v prints it anyway, otherwise on stderr so it stays out of -p's tree
+    if conf.seed_picked && conf.verbosity == 0 {
+        eprintln!("seed: {}", conf.seed);
+    }
+
+    if conf.verbosity > 0 {
+        println!("seed: {}", conf.seed);
+        if conf.persist {
+            let age_days = (now() - conf.born) / 86400;
+            println!("born: {} ({} days ago), branches: {}", civil_date(conf.born), age_days, my_counters.branches);
+        }
+    }
+}
+
+// unix time to yyyy-mm-dd (utc), the days-to-civil algorithm from Howard Hinnant's date library
+fn civil_date(time: i64) -> String {
+    let z = time.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// a new tree starts out this big
+const SEEDLING_BRANCHES: i32 = 10;
+
+// the life a tree needs to grow about this many branches by itself. cbonsai's tables grow around 480 at -L 32
+// and twice as many every 6 or 7 more, rounded up to 7 so the tree has a little more to grow than it may
+fn persistent_life(branches: i32) -> i32 {
+    let life = 32.0 + 7.0 * (branches.max(1) as f64 / 480.0).log2();
+    (life.round() as i32).clamp(4, 200)
+}
+
+// how far this run grows a --persist tree: what it had at the last save plus what grew since, up to the cap.
+// saved only moves on by the time the new branches took, so runs closer together than a branch still add up.
+// the tree gets the life to grow that far, a young tree is a small whole tree and an old one keeps getting bigger
+fn persistent_branch_limit(conf: &mut Config, now: i64) {
+    if conf.born == 0 {
+        conf.born = now;
+        conf.saved = now;
+        conf.branch_limit = SEEDLING_BRANCHES;
+    } else {
+        let seconds = (now - conf.saved).max(0);
+        let grown = (seconds as f64 / 3600.0 * conf.growth_rate as f64) as i32;
+        conf.branch_limit = conf.target_branch_count + grown;
+        conf.saved = if conf.growth_rate > 0.0 {
+            conf.saved + (grown as f64 * 3600.0 / conf.growth_rate as f64) as i64
+        } else {
+            now
+        };
+    }
+
+    if conf.max_branches > 0 && conf.branch_limit >= conf.max_branches {
+        conf.branch_limit = conf.max_branches;
+        // fully grown, nothing to carry over
+        conf.saved = now;
     }
+    conf.branch_limit = conf.branch_limit.max(1);
+    conf.life_start = persistent_life(conf.branch_limit);
+}
+
+// 0 means no seed was given, so never hand it out
+fn pick_seed() -> i32 {
+    thread_rng().gen_range(1..=i32::MAX)
 }
 
 fn print_help() {
@@ -159,440 +216,226 @@ fn print_help() {
     println!("  -i, --infinite         infinite mode: keep growing trees");
     println!("  -w, --wait=TIME        in infinite mode, wait TIME secs between each tree");
     println!("                           generation [default: 4.00]");
-    println!("  -S, --screensaver      screensaver mode; equivalent to -lie and");
-    println!("                           quit on any keypress");
+    println!("  -S, --screensaver      screensaver mode; equivalent to -l -i -W -C");
+    println!("                           and quit on any keypress");
     println!("  -m, --message=STR      attach message next to the tree");
     println!("  -b, --base=INT         acsii-art plant base to use, 0 is none");
-    println!("  -c, --leaf=LIST        list of comma-delimited strings randomly chosen");
+    println!("  -c, --leaf=LIST        list of comma-delimited characters randomly chosen");
     println!("                           for leaves");
     println!("  -M, --multiplier=INT   branch multiplier; higher -> more");
-    println!("                           branching (0-20) [default = 5]");
+    println!("                           branching (1-20) [default = 5]");
     println!("  -L, --life=INT         life; higher -> more growth (0-200) [default: 32]");
     println!("  -p, --print            print tree to terminal when finished");
-    println!("  -s, --seed=INT         seed random number generator");
+    println!("  -s, --seed=INT         seed random number generator, without it a random");
+    println!("                           seed is picked and printed on stderr at the end");
     println!("  -W, --save=FILE        save progress to file [default: $XDG_CACHE_HOME/rbonsai or $HOME/.cache/rbonsai]");
     println!("  -C, --load=FILE        load progress from file [default: $XDG_CACHE_HOME/rbonsai or $HOME/.cache/rbonsai]");
     println!("  -v, --verbose          increase output verbosity");
     println!("  -h, --help             show help");
+    println!("      --rules=FILE       read branch movement and spawn probabilities from a");
+    println!("                           TOML (or .json) rules file");
+    println!("      --style=NAME       grow in a classic bonsai style, NAME is one of");
+    println!("                           formal-upright, informal-upright, cascade,");
+    println!("                           windswept or literati");
+    println!("      --species=NAME     grow and draw like a pine, maple, willow, cherry or");
+    println!("                           juniper, --style and --rules still shape the tree");
+    println!("      --wind=STRENGTH    blow every branch sideways, -1.0 (left) to 1.0 (right)");
+    println!("      --lean=STRENGTH    lean the trunk, -1.0 (left) to 1.0 (right)");
+    println!("      --gusts            let the --wind come in gusts while the tree grows");
+    println!("      --collisions=MODE  what growth does with cells that are already taken:");
+    println!("                           overwrite (default), skip, leaves (only leaves");
+    println!("                           get drawn over) or steer (leaves, and branches");
+    println!("                           turn aside from the trunk and shoots)");
+    println!("      --auto-scale       shrink life and multiplier to fit terminals smaller");
+    println!("                           than 80x24");
+    println!("      --trees=N          grow N trees (1-32) side by side, each in its own pot;");
+    println!("                           fewer if the width doesn't fit N pots");
+    println!("      --forest           grow as many trees side by side as fit");
+    println!("      --blossoms[=DENSITY]  grow blossoms on leafy tips, DENSITY is the chance");
+    println!("                           per step from 0.0 to 1.0 [default: 0.15]");
+    println!("      --fruit[=DENSITY]  hang fruit below leafy tips [default: 0.05]");
+    println!("      --roots[=N]        grow N roots over the rim of the pot before the trunk");
+    println!("                           [default: 4]");
+    println!("      --seasons[=SECS]   let finished trees go through the seasons, a year");
+    println!("                           takes SECS [default: 120]. in infinite mode every");
+    println!("                           tree lives a year, otherwise until a key is pressed");
+    println!("      --persist          keep one living tree in the save file that grows a bit");
+    println!("                           more every run, the longer since the last run the");
+    println!("                           more it grows, its life (-L) growing with it.");
+    println!("                           implies --save and --load");
+    println!("      --growth-rate=N    with --persist, branches grown per hour [default: 2]");
+    println!("      --max-branches=N   with --persist, stop growing at N branches");
+    println!("      --weather=WEATHER  rain, snow or none [default: none]. snow settles on the");
+    println!("                           tree and the pot");
+    println!("      --backend=BACKEND  ncurses, ansi (escape codes, no ncurses needed) or plain");
+    println!("                           (text without colors) [default: ncurses, ansi when");
+    println!("                           built without the ncurses feature]. only ncurses reads");
+    println!("                           keys");
+    println!("      --prune            when the tree is done, move a cursor over it with the");
+    println!("                           arrow keys or hjkl and cut branches with space or x,");
+    println!("                           new buds grow back where they were cut. q or enter");
+    println!("                           when done");
+    println!("      --headless         grow without a terminal and print the tree as text,");
+    println!("                           with colors if -p is given");
+    println!("      --width=COLS       columns to grow in with --headless or the ansi and plain");
+    println!("                           backends [default: terminal width, 80 headless]");
+    println!("      --height=ROWS      rows to grow in, the same way [default: terminal");
+    println!("                           height, 24 headless]");
+    println!("      --export-svg=FILE  write the finished tree, pot and message to FILE as SVG");
+    println!("      --export-png=FILE  the same as PNG, drawn with a built-in bitmap font");
+    println!("      --font=FAMILY      font of the SVG text [default: monospace]");
+    println!("      --cell-size=WxH    pixels per character cell in exports [default: 10x20 in");
+    println!("                           SVG, 16x26 in PNG]");
+    println!("      --background=COLOR exported background, #rrggbb or none [default: #000000]");
+    println!();
+    println!("In live mode the arrow keys (or hjkl) wire the branch that is growing,");
+    println!("bending it that way for the rest of its life.");
 }
 
-fn draw_base(base_win: WINDOW, base_type: i32) {
-    match base_type {
-        1 => {
-            wattron(base_win, A_BOLD() | COLOR_PAIR(8));
-            wprintw(base_win, ":");
-            wattron(base_win, COLOR_PAIR(2));
-            wprintw(base_win, "___________");
-            wattron(base_win, COLOR_PAIR(11));
-            wprintw(base_win, "./~~~\\.");
-            wattron(base_win, COLOR_PAIR(2));
-            wprintw(base_win, "___________");
-            wattron(base_win, COLOR_PAIR(8));
-            wprintw(base_win, ":");
-
-            mvwprintw(base_win, 1, 0, " \\                           / ");
-            mvwprintw(base_win, 2, 0, "  \\_________________________/ ");
-            mvwprintw(base_win, 3, 0, "  (_)                     (_)");
-
-            wattr_off(base_win, A_BOLD());
-        },
-        2 => {
-            wattron(base_win, COLOR_PAIR(8));
-            wprintw(base_win, "(");
-            wattron(base_win, COLOR_PAIR(2));
-            wprintw(base_win, "---");
-            wattron(base_win, COLOR_PAIR(11));
-            wprintw(base_win, "./~~~\\.");
-            wattron(base_win, COLOR_PAIR(2));
-            wprintw(base_win, "---");
-            wattron(base_win, COLOR_PAIR(8));
-            wprintw(base_win, ")");
-
-            mvwprintw(base_win, 1, 0, " (           ) ");
-            mvwprintw(base_win, 1, 0, "  (_________)  ");
-        },
-        _ => (),
-    }
-}
-
-fn draw_wins(base_type: i32, objects: &mut NcursesObjects) {
-    let mut base_width = 0;
-    let mut base_height = 0;
-    let mut rows = 0;
-    let mut cols = 0;
-
-    match base_type {
-        1 => {
-            base_width = 31;
-            base_height = 4;
-        },
-        2=> {
-            base_width = 15;
-            base_height = 3;
-        },
-        _ => ()
+// --backend=ansi and plain
+fn grow_in_terminal(mut conf: Config, rules: &dyn GrowthRules) {
+    let (rows, cols) = render::terminal_size().unwrap_or((24, 80));
+    let rows = if conf.height > 0 { conf.height } else { rows };
+    let cols = if conf.width > 0 { conf.width } else { cols };
+    if conf.auto_scale {
+        scene::fit_to_grid(&mut conf, rows, cols);
     }
 
-    getmaxyx(stdscr(), &mut rows, &mut cols);
-    let base_origin_y = rows - base_height;
-    let base_origin_x = (cols / 2) - (base_width / 2);
-
-    objects.base_win = Some(newwin(base_height, base_width, base_origin_y, base_origin_x));
-    objects.tree_win = Some(newwin(rows - base_height, cols, 0, 0));
-
-    if objects.base_panel != None {
-        let p = objects.base_panel.expect("could not get base_panel");
-        let w = objects.base_win.expect("could not get base_win");
-        replace_panel(p, w);
+    // only the ansi backend can show the growth, plain text comes out once it's done.
+    // either way the screen is kept for the exports
+    let mut screen = TextRenderer::new(rows, cols, false);
+    let my_counters = if conf.backend == Backend::Ansi {
+        let mut renderer = AnsiRenderer::new(rows, cols);
+        scene::grow_in_memory(&conf, rules, &mut Tee(&mut renderer, &mut screen), conf.live)
     } else {
-        objects.base_panel = Some(new_panel(objects.base_win.expect("could not get base_win")));
-    }
-
-    if objects.tree_panel != None {
-        let p = objects.tree_panel.expect("could not get tree_panel");
-        let w = objects.tree_win.expect("could not get tree_win");
-        replace_panel(p, w);
-    } else {
-        objects.tree_panel = Some(new_panel(objects.tree_win.expect("could not get tree_win")));
-    }
-
-    draw_base(objects.base_win.expect("could not get base_win"), base_type);
-}
-
-fn roll(dice: &mut i32, m: i32) {
-    let mut rng = thread_rng();
-    *dice = rng.gen_range(0..m);
-}
-
-fn check_key_press(conf: &Config, my_counters: &Counters) -> bool {
-    if conf.screensaver && wgetch(stdscr()) != ERR || (wgetch(stdscr()) == 'q' as i32) {
-        finish(conf, my_counters);
-        return true;
-    }
-    false
-}
-
-fn update_screen(time_step: f32) {
-    update_panels();
-    doupdate();
+        let my_counters = scene::grow_in_memory(&conf, rules, &mut screen, false);
+        print!("{}", screen.text());
+        my_counters
+    };
 
-    let dur = Duration::from_secs_f32(time_step);
-    thread::sleep(dur);
+    wrap_up(&conf, &my_counters, Some(&screen));
 }
 
-fn choose_color(b_type: BranchType, tree_win: WINDOW) {
-    let mut rng = thread_rng();
-
-    match b_type {
-        BranchType::Trunk | BranchType::ShootLeft | BranchType::ShootRight => {
-            if rng.gen_range(0..2) == 0 {
-                wattron(tree_win, A_BOLD() | COLOR_PAIR(11));
-            } else {
-                wattron(tree_win, COLOR_PAIR(3));
-            }
+fn main() {
+    let mut conf = match args::parse_args(env::args().skip(1)) {
+        Ok(Command::Grow(conf)) => *conf,
+        Ok(Command::Help) => {
+            print_help();
+            exit(0);
         },
-        BranchType::Dying => {
-            if rng.gen_range(0..10) == 0 {
-                wattron(tree_win, A_BOLD() | COLOR_PAIR(2));
-            } else {
-                wattron(tree_win, COLOR_PAIR(2));
-            }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!("try 'rbonsai --help' for more information");
+            exit(1);
         },
-        BranchType::Dead => {
-            if rng.gen_range(0..3) == 0 {
-                wattron(tree_win, A_BOLD() | COLOR_PAIR(10));
-            } else {
-                wattron(tree_win, COLOR_PAIR(10));
-            }
-        }
-    }
-}
+    };
 
-fn set_deltas(b_type: BranchType, life: i32, age: i32, multiplier: i32, return_dx: &mut i32, return_dy: &mut i32) {
-    let mut dx: i32 = 0;
-    let mut dy: i32 = 0;
-    let mut dice: i32 = 0;
-
-    let mut rng = thread_rng();
-
-    match b_type {
-        BranchType::Trunk => {
-            if age <= 2 || life < 4 {
-                dy = 0;
-                dx = rng.gen_range(0..3) - 1;
-            } else if age < multiplier * 3 {
-                if age % (multiplier * 0.5 as i32) == 0 { dy = -1; } else { dy = 0; }
-
-                roll(&mut dice, 10);
-                if dice == 0 { dx = -2; }
-                else if (1..=3).contains(&dice) { dx = -1; }
-                else if (4..=5).contains(&dice) { dx = 0; }
-                else if (6..=8).contains(&dice) { dx = 1; }
-                else if dice == 9 { dx = 2; }
-            } else {
-                roll(&mut dice, 10);
-                if dice > 2 { dy = -1; }
-                else { dy = 0; }
-                dx = rng.gen_range(0..3) - 1;
-            }
-        },
-        BranchType::ShootLeft => {
-            roll(&mut dice, 10);
-            if (0..=1).contains(&dice) { dy = -1; }
-            else if (2..=7).contains(&dice) { dy = 0; }
-            else if (8..=9).contains(&dice) { dy = 1; }
-
-            roll(&mut dice, 10);
-            if (0..=1).contains(&dice) { dx = -2; }
-            else if (2..=5).contains(&dice) { dx = -1; }
-            else if (6..=8).contains(&dice) { dx = 0; }
-            else if dice == 9 { dx = 1; }
-        },
-        BranchType::ShootRight => {
-            roll(&mut dice, 10);
-            if (0..=1).contains(&dice) { dy = -1; }
-            else if (2..=7).contains(&dice) { dy = 0; }
-            else if (8..=9).contains(&dice) { dy = 1; }
-
-            roll(&mut dice, 10);
-            if (0..=1).contains(&dice) { dx = 2; }
-            else if (2..=5).contains(&dice) { dx = 1; }
-            else if (6..=8).contains(&dice) { dx = 0; }
-            else if dice == 9 { dx = -1; }
-        },
-        BranchType::Dying => {
-            roll(&mut dice, 10);
-            if (0..=1).contains(&dice) { dy = -1; }
-            else if (2..=8).contains(&dice) { dy = 0; }
-            else if dice == 9 { dy = 1; }
-
-            roll(&mut dice, 15);
-            if dice == 0 { dx = -3; }
-            else if (1..=2).contains(&dice) { dx = -2; }
-            else if (3..=5).contains(&dice) { dx = -1; }
-            else if (6..=8).contains(&dice) { dx = 0; }
-            else if (9..=11).contains(&dice) { dx = 1; }
-            else if (12..=13).contains(&dice) { dx = 2; }
-            else if dice == 14 { dx = 3; }
-        },
-        BranchType::Dead => {
-            roll(&mut dice, 10);
-            if (0..=2).contains(&dice) { dy = -1; }
-            else if (3..=6).contains(&dice) { dy = 0; }
-            else if (7..=9).contains(&dice) { dy = 1; }
-            dx = rng.gen_range(0..3) - 1;
-        }
+    if conf.load {
+        load_from_file(&mut conf);
     }
 
-    *return_dx = dx;
-    *return_dy = dy;
-}
-
-fn choose_string(conf: &Config, mut b_type: BranchType, life: i32, dx: i32, dy: i32) -> String {
-    const MAX_STR_LEN: usize = 32;
-    let mut branch_str: String = String::with_capacity(MAX_STR_LEN);
-
-    branch_str.push('?');
-    if life < 4 { b_type = BranchType::Dying };
-
-    match b_type {
-        BranchType::Trunk => {
-            if dy == 0 { branch_str = "/~".to_string(); }
-            else if dx < 0 { branch_str = "\\|".to_string(); }
-            else if dx == 0 { branch_str = "/|\\".to_string(); }
-            else if dx > 0 { branch_str = "|/".to_string(); }
-        },
-        BranchType::ShootLeft => {
-            if dy > 0 { branch_str = "\\".to_string(); }
-            else if dy == 0 { branch_str = "\\_".to_string(); }
-            else if dx < 0 { branch_str = "\\|".to_string(); }
-            else if dx == 0 { branch_str = "/|".to_string(); }
-            else if dx > 0 { branch_str = "/".to_string(); }
-        },
-        BranchType::ShootRight => {
-            if dy > 0 { branch_str = "/".to_string(); }
-            else if dy == 0 { branch_str = "_/".to_string(); }
-            else if dx < 0 { branch_str = "\\|".to_string(); }
-            else if dx == 0 { branch_str = "/|".to_string(); }
-            else if dx > 0 { branch_str = "/".to_string(); }
-        },
-        BranchType::Dying | BranchType::Dead => {
-            let mut rng = thread_rng();
-
-            branch_str.clear();
-            let i: i32 = rng.gen_range(0..conf.leaves_size); // does this emulate the og?
-            let c: char = conf.leaves[i as usize];
-
-            for _ in 0..MAX_STR_LEN {
-                branch_str.push(c);
-            }
-        }
+    if conf.persist {
+        persistent_branch_limit(&mut conf, now());
     }
 
-    branch_str.shrink_to_fit();
-    branch_str
-}
-
-#[allow(unused_assignments)] // 'age is assigned but not used' warning
-fn branch(conf: &Config, objects: &NcursesObjects, my_counters: &mut Counters, mut y: i32, mut x: i32, b_type: BranchType, mut life: i32) {
-    my_counters.branches += 1;
-    let mut dx: i32 = 0;
-    let mut dy: i32 = 0;
-    let mut age: i32 = 0;
-    let mut shoot_cooldown: i32 = conf.multiplier;
-
-    let mut rng = thread_rng();
-
-    while life > 0 {
-        if check_key_press(conf, my_counters) {
-            quit(conf, objects, 0);
-        }
-
-        life -= 1;
-        age = conf.life_start - life;
-
-        set_deltas(b_type, life, age, conf.multiplier, &mut dx, &mut dy);
-
-        let max_y: i32 = getmaxy(objects.tree_win.unwrap());
-        if dy > 0 && y > (max_y - 2) { dy -= 1; }
-
-        if life < 3 {
-            branch(conf, objects, my_counters, y, x, BranchType::Dead, life)
-        } else if (b_type == BranchType::Trunk || b_type == BranchType::ShootLeft || b_type == BranchType::ShootRight) && life < (conf.multiplier + 2) {
-            branch(conf, objects, my_counters, y, x, BranchType::Dying, life);
-        } else if (b_type == BranchType::Trunk && rng.gen_range(0..3) == 0) || (life % conf.multiplier == 0) {
-            if rng.gen_range(0..8) == 0 && life > 7 {
-                shoot_cooldown = conf.multiplier * 2;
-                branch(conf, objects, my_counters, y, x, BranchType::Trunk, life + rng.gen_range(0..5) - 2);
-            } else if shoot_cooldown <= 0 {
-                shoot_cooldown = conf.multiplier * 2;
-
-                let shoot_life: i32 = life + conf.multiplier;
-
-                my_counters.shoots += 1;
-                my_counters.shoot_counter += 1;
-                if conf.verbosity > 0 {
-                    mvwprintw(objects.tree_win.unwrap(), 4, 5, format!("shoots: {}", my_counters.shoots).as_str());
-                }
+    if conf.seed == 0 {
+        conf.seed = pick_seed();
+        conf.seed_picked = true;
+    }
 
-                branch(conf, objects, my_counters, y, x, BranchType::from_i32((my_counters.shoot_counter % 2) + 1), shoot_life);
-            }
+    // a bad rules file should be reported before the terminal is taken over.
+    // the rules only look at --style, --rules, --species and the wind, the conf that grows is moved into the closure
+    let rules_conf = conf.clone();
+    let grown = rbonsai::with_rules(&rules_conf, move |rules| {
+        if conf.headless {
+            let (screen, my_counters) = rbonsai::grow_headless_by(&conf, rules);
+            print!("{}", screen.text());
+            wrap_up(&conf, &my_counters, Some(&screen));
+            return;
         }
-        shoot_cooldown -= 1;
 
-        if conf.verbosity > 0 {
-            mvwprintw(objects.tree_win.unwrap(), 5, 5, format!("dx: {}", dx).as_str());
-            mvwprintw(objects.tree_win.unwrap(), 6, 5, format!("dy: {}", dy).as_str());
-            mvwprintw(objects.tree_win.unwrap(), 7, 5, format!("type: {}", b_type).as_str());
-            mvwprintw(objects.tree_win.unwrap(), 8, 5, format!("dx: {}", dx).as_str());
+        match conf.backend {
+            #[cfg(feature = "ncurses")]
+            Backend::Ncurses => curses::run(conf, rules),
+            _ => grow_in_terminal(conf, rules),
         }
+    });
 
-        x += dx;
-        y += dy;
-
-        choose_color(b_type, objects.tree_win.unwrap());
+    if let Err(e) = grown {
+        eprintln!("error: {}", e);
+        exit(1);
+    }
+}
 
-        let branch_str: String = choose_string(conf, b_type, life, dx, dy);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        // i do not think i need to do anything with wide characters,
-        // i think rust handles unicode stuff better by default than C
-        //
-        // i could be (probably am) wrong but thats a problem for another time
+    const HOUR: i64 = 3600;
+    const NOW: i64 = 1_760_000_000;
 
-        mvwprintw(objects.tree_win.unwrap(), y, x, branch_str.as_str());
+    fn persisted(target_branch_count: i32, saved: i64, max_branches: i32) -> Config {
+        Config { persist: true, born: NOW - 100 * HOUR, saved, target_branch_count, max_branches, ..Config::default() }
+    }
 
-        wattroff(objects.tree_win.unwrap(), A_BOLD());
+    #[test]
+    fn a_new_tree_is_a_seedling() {
+        let mut conf = Config { persist: true, ..Config::default() };
+        persistent_branch_limit(&mut conf, NOW);
+        assert_eq!((conf.branch_limit, conf.born, conf.saved), (SEEDLING_BRANCHES, NOW, NOW));
 
-        if conf.live && !(conf.load && my_counters.branches < conf.target_branch_count) {
-            update_screen(conf.time_step);
-        }
+        let mut conf = Config { persist: true, max_branches: 4, ..Config::default() };
+        persistent_branch_limit(&mut conf, NOW);
+        assert_eq!(conf.branch_limit, 4);
     }
-}
 
-fn add_spaces(message_win: WINDOW, count: i32, line_position: &mut i32, max_width: i32) {
-    if *line_position < (max_width - count) {
-        for _ in 0..count {
-            wprintw(message_win, " ");
-            *line_position += 1;
+    #[test]
+    fn no_time_no_growth() {
+        for saved in [NOW, NOW + HOUR] {
+            let mut conf = persisted(30, saved, 0);
+            persistent_branch_limit(&mut conf, NOW);
+            assert_eq!((conf.branch_limit, conf.saved), (30, saved));
         }
-    }
-}
-
-fn create_message_windows(objects: &mut NcursesObjects, message: &str) {
-    let mut max_y: i32 = 0;
-    let mut max_x: i32 = 0;
-    getmaxyx(stdscr(), &mut max_y, &mut max_x);
 
-    let box_width: i32;
-    let box_height: i32;
-
-    if (message.len() + 3) as f32 <= (0.25 * max_x as f32) {
-        box_width = (message.len() + 1) as i32;
-        box_height = 1;
-    } else {
-        box_width = (0.25 * max_x as f32) as i32;
-        box_height = (message.len() as i32 / box_width) + (message.len() as i32 / box_width);
+        // even an empty tree grows at least a branch
+        let mut conf = persisted(0, NOW, 0);
+        persistent_branch_limit(&mut conf, NOW);
+        assert_eq!(conf.branch_limit, 1);
     }
 
-    objects.message_border_win = Some(newwin(box_height + 2, box_width + 4, (max_y as f32 * 0.7) as i32 - 1, (max_x as f32 * 0.7) as i32 - 2));
-    objects.message_win = Some(newwin(box_height, box_width + 1, (max_y as f32 * 0.7) as i32, (max_x as f32 * 0.7) as i32));
-
-    wattron(objects.message_border_win.unwrap(), COLOR_PAIR(8) | A_BOLD());
-    wborder(objects.message_border_win.unwrap(), '|' as u32, '|' as u32, '-' as u32, '-' as u32, '+' as u32, '+' as u32, '+' as u32, '+' as u32);
-
-    if objects.message_border_panel.is_some() {
-        replace_panel(objects.message_border_panel.unwrap(), objects.message_border_win.unwrap());
-    } else {
-        objects.message_border_panel = Some(new_panel(objects.message_border_win.unwrap()));
+    #[test]
+    fn growth_rate_carries_over_what_didnt_grow() {
+        // 2 an hour, 100 minutes grow 3 branches in 90 of them, the other 10 count towards the next run
+        let mut conf = persisted(30, NOW - 100 * 60, 0);
+        persistent_branch_limit(&mut conf, NOW);
+        assert_eq!((conf.branch_limit, conf.saved), (33, NOW - 10 * 60));
     }
 
-    if objects.message_panel.is_some() {
-        replace_panel(objects.message_panel.unwrap(), objects.message_win.unwrap());
-    } else {
-        objects.message_panel = Some(new_panel(objects.message_win.unwrap()));
+    #[test]
+    fn max_branches_caps_growth() {
+        let mut conf = persisted(18, NOW - 5 * HOUR, 20);
+        persistent_branch_limit(&mut conf, NOW);
+        assert_eq!((conf.branch_limit, conf.saved), (20, NOW));
     }
-}
 
-fn main() {
-    let mut conf = Config {
-        live: false,
-        infinite: 0,
-        screensaver: false,
-        print_tree: 0,
-        verbosity: 0,
-        life_start: 32,
-        multiplier: 5,
-        base_type: 1,
-        seed: 0,
-        leaves_size: 0,
-        save: true,
-        load: true,
-        target_branch_count: 0,
-
-        time_wait: 4.0,
-        time_step: 0.03,
-
-        message: String::new(),
-        leaves: ['\0'; 64],
-        save_file: String::from("test_save_file"),
-        load_file: String::from("test_save_file"),
-    };
-
-    let my_counters = Counters {
-        branches: 7,
-        shoots: 10,
-        shoot_counter: 15,
-    };
+    #[test]
+    fn an_old_tree_keeps_getting_bigger() {
+        for seed in [1, 7, 42, 99, 1234] {
+            let mut conf = Config { persist: true, seed, ..Config::default() };
+            let mut now = NOW;
+            let mut sizes = Vec::new();
+
+            // a run every two weeks for half a year, each starting from the last one's save
+            for _ in 0..12 {
+                persistent_branch_limit(&mut conf, now);
+                let (_, my_counters) = rbonsai::grow_headless(&conf).unwrap();
+                sizes.push(my_counters.branches);
+
+                conf.target_branch_count = conf.branch_limit.max(my_counters.branches);
+                now += 14 * 24 * HOUR;
+            }
 
-    if conf.load {
-        load_from_file(&mut conf);
+            assert!(sizes.windows(2).all(|w| w[0] < w[1]), "seed {} grew {:?}", seed, sizes);
+        }
     }
-
-    initscr();
-    addstr("Hello, World!");
-    getch();
-    finish(&conf, &my_counters)
-    // endwin();
-
-    // save_to_file(&conf.save_file, 5, 10);
 }
@@ -0,0 +1,489 @@
+use std::fmt;
+use std::io::{self, Write};
+
+#[cfg(feature = "ncurses")]
+use ncurses::*;
+
+use crate::tree::Style;
+
+// where everything gets drawn, picked with --backend
+#[derive(PartialEq, Clone, Copy)]
+pub enum Backend {
+    // the curses interface with panels, live mode, keys...
+    Ncurses,
+    // escape codes straight to the terminal, no libncurses needed
+    Ansi,
+    // the finished tree as text, no colors
+    Plain,
+}
+
+pub const BACKEND_NAMES: &[(&str, Backend)] = &[
+    ("ncurses", Backend::Ncurses),
+    ("ansi", Backend::Ansi),
+    ("plain", Backend::Plain),
+];
+
+impl Backend {
+    pub fn from_name(name: &str) -> Option<Backend> {
+        let name = name.trim().to_lowercase();
+        BACKEND_NAMES.iter().find(|(n, _)| *n == name).map(|(_, b)| *b)
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = BACKEND_NAMES.iter().find(|(_, b)| b == self).map(|(n, _)| *n).unwrap_or("unknown");
+        write!(f, "{}", name)
+    }
+}
+
+// the terminal's default colors, what ncurses calls color pair 0
+pub const PLAIN: Style = Style { color: 0, bold: false };
+
+// something cells can be drawn on: a window, a terminal, a grid of text...
+pub trait Renderer {
+    // (rows, cols)
+    fn size(&self) -> (i32, i32);
+    // the style every cell is put in until it's set again
+    fn set_style(&mut self, style: Style);
+    // one glyph at (y, x), anything outside is dropped
+    fn put_cell(&mut self, y: i32, x: i32, glyph: char);
+    // shows what was put so far
+    fn flush(&mut self);
+}
+
+pub fn put_str(renderer: &mut dyn Renderer, y: i32, x: i32, s: &str) {
+    for (i, glyph) in s.chars().enumerate() {
+        renderer.put_cell(y, x + i as i32, glyph);
+    }
+}
+
+// an ncurses window, styles are the color pairs set up in init
+#[cfg(feature = "ncurses")]
+pub struct NcursesRenderer {
+    win: WINDOW,
+    style: Style,
+}
+
+#[cfg(feature = "ncurses")]
+impl NcursesRenderer {
+    pub fn new(win: WINDOW) -> NcursesRenderer {
+        NcursesRenderer { win, style: PLAIN }
+    }
+}
+
+#[cfg(feature = "ncurses")]
+impl Renderer for NcursesRenderer {
+    fn size(&self) -> (i32, i32) {
+        let (mut rows, mut cols) = (0, 0);
+        getmaxyx(self.win, &mut rows, &mut cols);
+        (rows, cols)
+    }
+
+    fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    // the window goes back to normal after every cell, so anything printed with mvwprintw stays plain
+    fn put_cell(&mut self, y: i32, x: i32, glyph: char) {
+        let mut attrs = COLOR_PAIR(self.style.color);
+        if self.style.bold { attrs |= A_BOLD(); }
+
+        wattrset(self.win, attrs);
+        mvwaddstr(self.win, y, x, glyph.encode_utf8(&mut [0; 4]));
+        wattrset(self.win, A_NORMAL());
+    }
+
+    fn flush(&mut self) {
+        update_panels();
+        doupdate();
+    }
+}
+
+// the color of a style as sgr parameters. the 16 palette colors are the 8 normal and 8 bright ones,
+// like ncurses on a 256 color terminal
+fn sgr(style: Style) -> String {
+    let color = match style.color {
+        0 => String::from("39"),
+        c @ 1..=7 => (30 + c).to_string(),
+        c => (90 + (c - 8).clamp(0, 7)).to_string(),
+    };
+
+    if style.bold { format!("0;1;{}", color) } else { format!("0;{}", color) }
+}
+
+// draws with escape codes straight to stdout, for terminals without (lib)ncurses
+pub struct AnsiRenderer {
+    rows: i32,
+    cols: i32,
+    style: Style,
+    // the style the terminal is in right now, so it's only changed when it has to be
+    current: Option<Style>,
+    out: String,
+}
+
+impl AnsiRenderer {
+    pub fn new(rows: i32, cols: i32) -> AnsiRenderer {
+        // clear the screen and hide the cursor while drawing
+        AnsiRenderer { rows, cols, style: PLAIN, current: None, out: String::from("\x1b[2J\x1b[?25l") }
+    }
+}
+
+impl Renderer for AnsiRenderer {
+    fn size(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    fn put_cell(&mut self, y: i32, x: i32, glyph: char) {
+        if y < 0 || y >= self.rows || x < 0 || x >= self.cols {
+            return;
+        }
+
+        self.out.push_str(&format!("\x1b[{};{}H", y + 1, x + 1));
+        if self.current != Some(self.style) {
+            self.out.push_str(&format!("\x1b[{}m", sgr(self.style)));
+            self.current = Some(self.style);
+        }
+        self.out.push(glyph);
+    }
+
+    fn flush(&mut self) {
+        let mut stdout = io::stdout();
+        // nowhere to show it if stdout is gone, nothing to do about that
+        let _ = stdout.write_all(self.out.as_bytes());
+        let _ = stdout.flush();
+        self.out.clear();
+    }
+}
+
+impl Drop for AnsiRenderer {
+    // leave the terminal the way it was, with the prompt below the tree
+    fn drop(&mut self) {
+        self.out.push_str(&format!("\x1b[0m\x1b[{};1H\x1b[?25h\n", self.rows));
+        self.flush();
+    }
+}
+
+// draws on two renderers at once, the size is the first one's
+pub struct Tee<'a>(pub &'a mut dyn Renderer, pub &'a mut dyn Renderer);
+
+impl Renderer for Tee<'_> {
+    fn size(&self) -> (i32, i32) {
+        self.0.size()
+    }
+
+    fn set_style(&mut self, style: Style) {
+        self.0.set_style(style);
+        self.1.set_style(style);
+    }
+
+    fn put_cell(&mut self, y: i32, x: i32, glyph: char) {
+        self.0.put_cell(y, x, glyph);
+        self.1.put_cell(y, x, glyph);
+    }
+
+    fn flush(&mut self) {
+        self.0.flush();
+        self.1.flush();
+    }
+}
+
+// a grid of styled cells in memory, read back as text (with or without colors)
+pub struct TextRenderer {
+    rows: i32,
+    cols: i32,
+    style: Style,
+    cells: Vec<(char, Style)>,
+    colors: bool,
+}
+
+impl TextRenderer {
+    pub fn new(rows: i32, cols: i32, colors: bool) -> TextRenderer {
+        let rows = rows.max(0);
+        let cols = cols.max(0);
+        TextRenderer { rows, cols, style: PLAIN, cells: vec![(' ', PLAIN); (rows * cols) as usize], colors }
+    }
+
+    // what is at (y, x), a plain space outside the grid
+    pub fn cell(&self, y: i32, x: i32) -> (char, Style) {
+        if y < 0 || y >= self.rows || x < 0 || x >= self.cols {
+            return (' ', PLAIN);
+        }
+        self.cells[(y * self.cols + x) as usize]
+    }
+
+    // every row, trailing spaces cut off. colors are escape codes, reset at the end of each row
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+
+        for row in self.cells.chunks(self.cols.max(1) as usize) {
+            let end = row.iter().rposition(|&(glyph, _)| glyph != ' ').map_or(0, |i| i + 1);
+            let mut current = PLAIN;
+
+            for &(glyph, style) in &row[..end] {
+                if self.colors && style != current {
+                    text.push_str(&format!("\x1b[{}m", sgr(style)));
+                    current = style;
+                }
+                text.push(glyph);
+            }
+            if self.colors && current != PLAIN {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+
+        // blank rows under the picture are left out
+        let end = text.trim_end_matches('\n').len();
+        text.truncate(end);
+        if end > 0 {
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+impl Renderer for TextRenderer {
+    fn size(&self) -> (i32, i32) {
+        (self.rows, self.cols)
+    }
+
+    fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    fn put_cell(&mut self, y: i32, x: i32, glyph: char) {
+        if y >= 0 && y < self.rows && x >= 0 && x < self.cols {
+            self.cells[(y * self.cols + x) as usize] = (glyph, self.style);
+        }
+    }
+
+    // nothing is shown until someone asks for text()
+    fn flush(&mut self) {}
+}
+
+// the rows and columns of the terminal on stdout, None if it isn't one
+pub fn terminal_size() -> Option<(i32, i32)> {
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+
+    if ok && size.ws_row > 0 && size.ws_col > 0 {
+        Some((size.ws_row as i32, size.ws_col as i32))
+    } else {
+        None
+    }
+}
+
+// (width, height) of the pot for --base
+pub fn base_size(base_type: i32) -> (i32, i32) {
+    match base_type {
+        1 => (31, 4),
+        2 => (15, 3),
+        _ => (0, 0),
+    }
+}
+
+// the pot art, row by row in pieces of one color
+fn base_rows(base_type: i32) -> Vec<Vec<(&'static str, Style)>> {
+    let gray = |bold| Style { color: 8, bold };
+    let green = |bold| Style { color: 2, bold };
+    let soil = |bold| Style { color: 11, bold };
+
+    match base_type {
+        1 => vec![
+            vec![(":", gray(true)), ("___________", green(true)), ("./~~~\\.", soil(true)), ("___________", green(true)), (":", gray(true))],
+            vec![(" \\                           / ", gray(true))],
+            vec![("  \\_________________________/ ", gray(true))],
+            vec![("  (_)                     (_)", gray(true))],
+        ],
+        // the second row has only ever shown the bottom of the pot
+        2 => vec![
+            vec![("(", gray(false)), ("---", green(false)), ("./~~~\\.", soil(false)), ("---", green(false)), (")", gray(false))],
+            vec![("  (_________)  ", gray(false))],
+        ],
+        _ => Vec::new(),
+    }
+}
+
+// the pot with its top left corner at (y, x)
+pub fn draw_base(renderer: &mut dyn Renderer, y: i32, x: i32, base_type: i32) {
+    for (row, pieces) in base_rows(base_type).into_iter().enumerate() {
+        let mut col = x;
+        for (text, style) in pieces {
+            renderer.set_style(style);
+            put_str(renderer, y + row as i32, col, text);
+            col += text.chars().count() as i32;
+        }
+    }
+}
+
+// where the message goes on a rows x cols screen, (y, x, height, width) of the text.
+// the border is a row above and below it and two columns to either side
+pub fn message_box(message: &str, rows: i32, cols: i32) -> (i32, i32, i32, i32) {
+    let (box_width, box_height) = if (message.len() + 3) as f32 <= (0.25 * cols as f32) {
+        ((message.len() + 1) as i32, 1)
+    } else {
+        // at least a column, or a screen under 4 wide divides by zero
+        let box_width = ((0.25 * cols as f32) as i32).max(1);
+        // twice the rows the text takes unwrapped, words that don't fit leave lines short.
+        // rounded up, a message just too long for one line still gets its rows
+        let rows = message.len().div_ceil(box_width as usize) as i32;
+        (box_width, (rows + rows).max(1))
+    };
+
+    ((rows as f32 * 0.7) as i32, (cols as f32 * 0.7) as i32, box_height, box_width + 1)
+}
+
+// the message word wrapped into height rows of width, the way it has always been printed into its window:
+// words that don't fit the line start the next one, spaces are dropped at the end of a line
+pub fn layout_message(message: &str, height: i32, width: i32) -> Vec<Vec<char>> {
+    let mut lines = vec![vec![' '; width.max(0) as usize]; height.max(0) as usize];
+    let (mut y, mut x) = (0, 0);
+
+    // like waddstr: wrap at the edge, '\n' goes to the start of the next line, nothing past the last one
+    let mut write = |s: &str| {
+        for c in s.chars() {
+            if y >= height {
+                return;
+            }
+            if c == '\n' {
+                y += 1;
+                x = 0;
+                continue;
+            }
+            lines[y as usize][x as usize] = c;
+            x += 1;
+            if x >= width {
+                y += 1;
+                x = 0;
+            }
+        }
+    };
+
+    let mut line_position = 0;
+    let mut word = String::new();
+
+    // a trailing '\0' flushes the last word
+    for c in message.chars().chain(std::iter::once('\0')) {
+        if !(c.is_whitespace() || c == '\0') {
+            word.push(c);
+            continue;
+        }
+
+        let word_length = word.chars().count() as i32;
+        if line_position + word_length <= width {
+            write(&word);
+            line_position += word_length;
+        } else {
+            write(&format!("\n{}", word));
+            line_position = word_length;
+        }
+        word.clear();
+
+        let spaces = match c {
+            ' ' => 1,
+            '\t' => 4,
+            '\n' => {
+                write("\n");
+                line_position = 0;
+                0
+            },
+            _ => 0,
+        };
+        if spaces > 0 && line_position < width - spaces {
+            write(&" ".repeat(spaces as usize));
+            line_position += spaces;
+        }
+    }
+
+    lines
+}
+
+// the border around a message box of height x width at (y, x)
+pub fn draw_message_border(renderer: &mut dyn Renderer, y: i32, x: i32, height: i32, width: i32) {
+    let (top, left, bottom, right) = (y - 1, x - 2, y + height, x + width);
+
+    renderer.set_style(Style { color: 8, bold: true });
+    for col in left..=right {
+        let edge = if col == left || col == right { '+' } else { '-' };
+        renderer.put_cell(top, col, edge);
+        renderer.put_cell(bottom, col, edge);
+    }
+    for row in top + 1..bottom {
+        renderer.put_cell(row, left, '|');
+        renderer.put_cell(row, right, '|');
+        renderer.set_style(PLAIN);
+        for col in left + 1..right {
+            renderer.put_cell(row, col, ' ');
+        }
+        renderer.set_style(Style { color: 8, bold: true });
+    }
+}
+
+// the message text, laid out by layout_message, with its top left at (y, x)
+pub fn draw_message_text(renderer: &mut dyn Renderer, y: i32, x: i32, lines: &[Vec<char>]) {
+    renderer.set_style(PLAIN);
+    for (row, line) in lines.iter().enumerate() {
+        for (col, &glyph) in line.iter().enumerate() {
+            renderer.put_cell(y + row as i32, x + col as i32, glyph);
+        }
+    }
+}
+
+// a message box and its border anywhere on a rows x cols screen
+pub fn draw_message(renderer: &mut dyn Renderer, message: &str) {
+    if message.is_empty() {
+        return;
+    }
+
+    let (rows, cols) = renderer.size();
+    let (y, x, height, width) = message_box(message, rows, cols);
+    draw_message_border(renderer, y, x, height, width);
+    draw_message_text(renderer, y, x, &layout_message(message, height, width));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(message: &str, height: i32, width: i32) -> Vec<String> {
+        layout_message(message, height, width).iter().map(|l| l.iter().collect::<String>().trim_end().to_string()).collect()
+    }
+
+    #[test]
+    fn words_that_dont_fit_start_the_next_line() {
+        assert_eq!(lines("a small bonsai", 3, 8), ["a small", "bonsai", ""]);
+    }
+
+    #[test]
+    fn newlines_break_the_line() {
+        assert_eq!(lines("one\ntwo", 2, 10), ["one", "two"]);
+    }
+
+    #[test]
+    fn nothing_past_the_last_line() {
+        assert_eq!(lines("one two three four", 2, 5), ["one", "two"]);
+    }
+
+    #[test]
+    fn long_words_wrap_at_the_edge() {
+        // like every word too long for the line, it starts on the next one
+        assert_eq!(lines("bonsai", 3, 4), ["", "bons", "ai"]);
+    }
+
+    #[test]
+    fn a_message_too_long_for_one_line_still_shows() {
+        let message = "a small bonsai";
+        let (_, _, height, width) = message_box(message, 24, 64);
+        assert!(height >= 1 && (height * width) as usize >= message.len());
+
+        let mut text = TextRenderer::new(24, 64, false);
+        draw_message(&mut text, message);
+        assert!(text.text().contains(message));
+    }
+}
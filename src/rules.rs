@@ -0,0 +1,324 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::tree::{BranchType, Style};
+
+// what the rules get to see of a growing branch
+pub struct BranchState {
+    pub branch_type: BranchType,
+    pub y: i32,
+    pub x: i32,
+    pub life: i32,
+    pub age: i32,
+    pub multiplier: i32,
+    pub shoot_cooldown: i32,
+    // strings the whole tree has placed so far, the tree's own clock
+    pub step: i32,
+    // chance per step that a leafy tip grows a blossom or a fruit
+    pub blossoms: f32,
+    pub fruit: f32,
+}
+
+pub enum Spawn {
+    Nothing,
+    Trunk { life: i32 },
+    // the engine alternates shoots between left and right
+    Shoot { life: i32 },
+    Blossom { life: i32 },
+    Fruit { life: i32 },
+}
+
+// everything the engine decides by rolling dice, so alternative growth styles don't need to touch the engine.
+// rules are asked in this order every step: deltas, decay, spawn (only without decay), style, glyphs
+pub trait GrowthRules {
+    // which way the branch moves this step
+    fn deltas(&self, rng: &mut StdRng, branch: &BranchState) -> (i32, i32);
+
+    // the dying thresholds: Some(type) grows a branch of that type from here with the remaining life
+    fn decay(&self, branch: &BranchState) -> Option<BranchType>;
+
+    // branching decisions for a healthy branch
+    fn spawn(&self, rng: &mut StdRng, branch: &BranchState) -> Spawn;
+
+    // steps after spawning before the branch may spawn again
+    fn shoot_cooldown(&self, multiplier: i32) -> i32;
+
+    fn style(&self, rng: &mut StdRng, branch_type: BranchType) -> Style;
+
+    // the string drawn where the branch moved to
+    fn glyphs(&self, rng: &mut StdRng, branch: &BranchState, dx: i32, dy: i32, leaves: &[char]) -> String;
+}
+
+// the original cbonsai tables
+pub struct CbonsaiRules;
+
+impl GrowthRules for CbonsaiRules {
+    fn deltas(&self, rng: &mut StdRng, branch: &BranchState) -> (i32, i32) {
+        let mut dx: i32 = 0;
+        let mut dy: i32 = 0;
+        set_deltas(rng, branch.branch_type, branch.life, branch.age, branch.multiplier, &mut dx, &mut dy);
+        (dx, dy)
+    }
+
+    fn decay(&self, branch: &BranchState) -> Option<BranchType> {
+        let b_type = branch.branch_type;
+
+        if b_type == BranchType::Blossom || b_type == BranchType::Fruit || b_type.is_root() {
+            None
+        } else if branch.life < 3 {
+            Some(BranchType::Dead)
+        } else if (b_type == BranchType::Trunk || b_type == BranchType::ShootLeft || b_type == BranchType::ShootRight) && branch.life < (branch.multiplier + 2) {
+            Some(BranchType::Dying)
+        } else {
+            None
+        }
+    }
+
+    fn spawn(&self, rng: &mut StdRng, branch: &BranchState) -> Spawn {
+        let life = branch.life;
+
+        // blossoms, fruit and roots are the end of the line
+        if branch.branch_type == BranchType::Blossom || branch.branch_type == BranchType::Fruit || branch.branch_type.is_root() {
+            return Spawn::Nothing;
+        }
+
+        if (branch.branch_type == BranchType::Trunk && rng.gen_range(0..3) == 0) || (life % branch.multiplier.max(1) == 0) {
+            if rng.gen_range(0..8) == 0 && life > 7 {
+                return Spawn::Trunk { life: life + rng.gen_range(0..5) - 2 };
+            } else if branch.shoot_cooldown <= 0 {
+                return Spawn::Shoot { life: life + branch.multiplier };
+            }
+        }
+
+        bloom(rng, branch)
+    }
+
+    fn shoot_cooldown(&self, multiplier: i32) -> i32 {
+        multiplier * 2
+    }
+
+    fn style(&self, rng: &mut StdRng, branch_type: BranchType) -> Style {
+        choose_color(rng, branch_type)
+    }
+
+    fn glyphs(&self, rng: &mut StdRng, branch: &BranchState, dx: i32, dy: i32, leaves: &[char]) -> String {
+        choose_string(leaves, rng, branch.branch_type, branch.life, dx, dy)
+    }
+}
+
+// blossoms and fruit only grow from dying branches, the leafy tips. nothing is rolled unless they're turned on
+pub fn bloom(rng: &mut StdRng, branch: &BranchState) -> Spawn {
+    if branch.branch_type != BranchType::Dying {
+        return Spawn::Nothing;
+    }
+
+    if branch.blossoms > 0.0 && rng.gen::<f32>() < branch.blossoms {
+        return Spawn::Blossom { life: 1 };
+    }
+    if branch.fruit > 0.0 && rng.gen::<f32>() < branch.fruit {
+        return Spawn::Fruit { life: 1 };
+    }
+
+    Spawn::Nothing
+}
+
+fn roll(rng: &mut StdRng, dice: &mut i32, m: i32) {
+    *dice = rng.gen_range(0..m);
+}
+
+pub fn choose_color(rng: &mut StdRng, b_type: BranchType) -> Style {
+    match b_type {
+        BranchType::Trunk | BranchType::ShootLeft | BranchType::ShootRight | BranchType::RootLeft | BranchType::RootRight => {
+            if rng.gen_range(0..2) == 0 {
+                Style { color: 11, bold: true }
+            } else {
+                Style { color: 3, bold: false }
+            }
+        },
+        BranchType::Dying => {
+            if rng.gen_range(0..10) == 0 {
+                Style { color: 2, bold: true }
+            } else {
+                Style { color: 2, bold: false }
+            }
+        },
+        BranchType::Dead => {
+            if rng.gen_range(0..3) == 0 {
+                Style { color: 10, bold: true }
+            } else {
+                Style { color: 10, bold: false }
+            }
+        },
+        BranchType::Blossom => {
+            if rng.gen_range(0..2) == 0 {
+                Style { color: 13, bold: true }
+            } else {
+                Style { color: 15, bold: true }
+            }
+        },
+        BranchType::Fruit => {
+            if rng.gen_range(0..4) == 0 {
+                Style { color: 11, bold: true }
+            } else {
+                Style { color: 9, bold: true }
+            }
+        },
+    }
+}
+
+pub fn set_deltas(rng: &mut StdRng, b_type: BranchType, life: i32, age: i32, multiplier: i32, return_dx: &mut i32, return_dy: &mut i32) {
+    let mut dx: i32 = 0;
+    let mut dy: i32 = 0;
+    let mut dice: i32 = 0;
+
+    match b_type {
+        BranchType::Trunk => {
+            if age <= 2 || life < 4 {
+                dy = 0;
+                dx = rng.gen_range(0..3) - 1;
+            } else if age < multiplier * 3 {
+                if age % (multiplier / 2).max(1) == 0 { dy = -1; } else { dy = 0; }
+
+                roll(rng, &mut dice, 10);
+                if dice == 0 { dx = -2; }
+                else if (1..=3).contains(&dice) { dx = -1; }
+                else if (4..=5).contains(&dice) { dx = 0; }
+                else if (6..=8).contains(&dice) { dx = 1; }
+                else if dice == 9 { dx = 2; }
+            } else {
+                roll(rng, &mut dice, 10);
+                if dice > 2 { dy = -1; }
+                else { dy = 0; }
+                dx = rng.gen_range(0..3) - 1;
+            }
+        },
+        BranchType::ShootLeft => {
+            roll(rng, &mut dice, 10);
+            if (0..=1).contains(&dice) { dy = -1; }
+            else if (2..=7).contains(&dice) { dy = 0; }
+            else if (8..=9).contains(&dice) { dy = 1; }
+
+            roll(rng, &mut dice, 10);
+            if (0..=1).contains(&dice) { dx = -2; }
+            else if (2..=5).contains(&dice) { dx = -1; }
+            else if (6..=8).contains(&dice) { dx = 0; }
+            else if dice == 9 { dx = 1; }
+        },
+        BranchType::ShootRight => {
+            roll(rng, &mut dice, 10);
+            if (0..=1).contains(&dice) { dy = -1; }
+            else if (2..=7).contains(&dice) { dy = 0; }
+            else if (8..=9).contains(&dice) { dy = 1; }
+
+            roll(rng, &mut dice, 10);
+            if (0..=1).contains(&dice) { dx = 2; }
+            else if (2..=5).contains(&dice) { dx = 1; }
+            else if (6..=8).contains(&dice) { dx = 0; }
+            else if dice == 9 { dx = -1; }
+        },
+        BranchType::Dying => {
+            roll(rng, &mut dice, 10);
+            if (0..=1).contains(&dice) { dy = -1; }
+            else if (2..=8).contains(&dice) { dy = 0; }
+            else if dice == 9 { dy = 1; }
+
+            roll(rng, &mut dice, 15);
+            if dice == 0 { dx = -3; }
+            else if (1..=2).contains(&dice) { dx = -2; }
+            else if (3..=5).contains(&dice) { dx = -1; }
+            else if (6..=8).contains(&dice) { dx = 0; }
+            else if (9..=11).contains(&dice) { dx = 1; }
+            else if (12..=13).contains(&dice) { dx = 2; }
+            else if dice == 14 { dx = 3; }
+        },
+        BranchType::Dead => {
+            roll(rng, &mut dice, 10);
+            if (0..=2).contains(&dice) { dy = -1; }
+            else if (3..=6).contains(&dice) { dy = 0; }
+            else if (7..=9).contains(&dice) { dy = 1; }
+            dx = rng.gen_range(0..3) - 1;
+        },
+        // right next to the tip, blossoms sit on top and fruit hangs below
+        BranchType::Blossom => {
+            dy = rng.gen_range(0..2) - 1;
+            dx = rng.gen_range(0..3) - 1;
+        },
+        BranchType::Fruit => {
+            dy = 1;
+            dx = rng.gen_range(0..3) - 1;
+        },
+        // outwards along the rim, now and then down into the pot
+        BranchType::RootLeft | BranchType::RootRight => {
+            roll(rng, &mut dice, 10);
+            if (0..=6).contains(&dice) { dy = 0; }
+            else { dy = 1; }
+
+            roll(rng, &mut dice, 10);
+            if (0..=5).contains(&dice) { dx = 1; }
+            else if (6..=8).contains(&dice) { dx = 2; }
+            else { dx = 0; }
+
+            if b_type == BranchType::RootLeft { dx = -dx; }
+        },
+    }
+
+    *return_dx = dx;
+    *return_dy = dy;
+}
+
+pub fn choose_string(leaves: &[char], rng: &mut StdRng, mut b_type: BranchType, life: i32, dx: i32, dy: i32) -> String {
+    const MAX_STR_LEN: usize = 32;
+    let mut branch_str: String = String::with_capacity(MAX_STR_LEN);
+
+    branch_str.push('?');
+    if life < 4 && b_type.is_wood() { b_type = BranchType::Dying };
+
+    match b_type {
+        BranchType::Trunk => {
+            if dy == 0 { branch_str = "/~".to_string(); }
+            else if dx < 0 { branch_str = "\\|".to_string(); }
+            else if dx == 0 { branch_str = "/|\\".to_string(); }
+            else if dx > 0 { branch_str = "|/".to_string(); }
+        },
+        BranchType::ShootLeft => {
+            if dy > 0 { branch_str = "\\".to_string(); }
+            else if dy == 0 { branch_str = "\\_".to_string(); }
+            else if dx < 0 { branch_str = "\\|".to_string(); }
+            else if dx == 0 { branch_str = "/|".to_string(); }
+            else if dx > 0 { branch_str = "/".to_string(); }
+        },
+        BranchType::ShootRight => {
+            if dy > 0 { branch_str = "/".to_string(); }
+            else if dy == 0 { branch_str = "_/".to_string(); }
+            else if dx < 0 { branch_str = "\\|".to_string(); }
+            else if dx == 0 { branch_str = "/|".to_string(); }
+            else if dx > 0 { branch_str = "/".to_string(); }
+        },
+        BranchType::Blossom => {
+            branch_str = if rng.gen_range(0..3) == 0 { "@".to_string() } else { "*".to_string() };
+        },
+        BranchType::Fruit => {
+            branch_str = "o".to_string();
+        },
+        BranchType::RootLeft => {
+            if dy > 0 { branch_str = "/".to_string(); }
+            else { branch_str = "~".to_string(); }
+        },
+        BranchType::RootRight => {
+            if dy > 0 { branch_str = "\\".to_string(); }
+            else { branch_str = "~".to_string(); }
+        },
+        BranchType::Dying | BranchType::Dead => {
+            branch_str.clear();
+            let i: i32 = rng.gen_range(0..leaves.len() as i32); // does this emulate the og?
+            let c: char = leaves[i as usize];
+
+            for _ in 0..MAX_STR_LEN {
+                branch_str.push(c);
+            }
+        }
+    }
+
+    branch_str.shrink_to_fit();
+    branch_str
+}
@@ -0,0 +1,428 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::rules::{bloom, BranchState, CbonsaiRules, GrowthRules, Spawn};
+use crate::tree::{BranchType, Style};
+
+#[derive(Debug)]
+pub enum RulesError {
+    Read { file: String, reason: String },
+    Parse { file: String, reason: String },
+    Invalid { file: String, table: &'static str, reason: String },
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::Read { file, reason } => write!(f, "couldn't read rules file {}: {}", file, reason),
+            RulesError::Parse { file, reason } => write!(f, "couldn't parse rules file {}: {}", file, reason),
+            RulesError::Invalid { file, table, reason } => write!(f, "rules file {}: {}: {}", file, table, reason),
+        }
+    }
+}
+
+// the most a rules file can move a branch in one step, and the largest new_trunk_life_jitter and shoot_cooldown
+const MAX_DELTA: i32 = 100;
+const MAX_SPAWN_STEPS: i32 = 100;
+
+// a die with `roll` sides, each [delta, weight] pair claims `weight` consecutive sides in order.
+// e.g. { roll = 10, weights = [[-1, 2], [0, 6], [1, 2]] } is cbonsai's `0..=1 -> -1, 2..=7 -> 0, 8..=9 -> 1`
+#[derive(Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct DeltaTable {
+    roll: i32,
+    weights: Vec<(i32, i32)>,
+}
+
+impl DeltaTable {
+    pub(crate) fn new(roll: i32, weights: &[(i32, i32)]) -> DeltaTable {
+        DeltaTable { roll, weights: weights.to_vec() }
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> i32 {
+        // a one sided die isn't rolled, like cbonsai's fixed deltas (fruit always hangs down)
+        let mut dice: i32 = if self.roll == 1 { 0 } else { rng.gen_range(0..self.roll) };
+
+        for &(delta, weight) in &self.weights {
+            if dice < weight {
+                return delta;
+            }
+            dice -= weight;
+        }
+
+        // validate() makes sure the weights cover the whole roll
+        unreachable!("delta table weights don't cover the roll")
+    }
+
+    fn validate(&self, file: &str, table: &'static str) -> Result<(), RulesError> {
+        let invalid = |reason: String| RulesError::Invalid { file: file.to_string(), table, reason };
+
+        if self.roll < 1 {
+            return Err(invalid(format!("roll must be at least 1, got {}", self.roll)));
+        }
+
+        if let Some(&(delta, _)) = self.weights.iter().find(|(d, _)| !(-MAX_DELTA..=MAX_DELTA).contains(d)) {
+            return Err(invalid(format!("delta {} is more than {} cells in one step", delta, MAX_DELTA)));
+        }
+
+        if let Some(&(delta, weight)) = self.weights.iter().find(|(_, w)| *w < 0) {
+            return Err(invalid(format!("weight for delta {} must not be negative, got {}", delta, weight)));
+        }
+
+        let covered: i64 = self.weights.iter().map(|(_, w)| *w as i64).sum();
+        if covered != self.roll as i64 {
+            return Err(invalid(format!("weights cover {} of the {} roll values, they must add up to the roll exactly", covered, self.roll)));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct TrunkTables {
+    // first couple of steps and the last few (age <= 2 or life < 4), the trunk never rises here
+    pub(crate) sapling_dx: DeltaTable,
+    // age < multiplier * 3, without young_dy the trunk rises every multiplier / 2 steps
+    pub(crate) young_dx: DeltaTable,
+    pub(crate) young_dy: Option<DeltaTable>,
+    pub(crate) mature_dx: DeltaTable,
+    pub(crate) mature_dy: DeltaTable,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BranchTables {
+    pub(crate) dx: DeltaTable,
+    pub(crate) dy: DeltaTable,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct SpawnTable {
+    // a trunk tries to branch on 1 in this many steps, every branch also tries on each multiplier-th step of life
+    pub(crate) trunk_branch_roll: i32,
+    // 1 in this many branching attempts starts a new trunk instead of a shoot
+    pub(crate) new_trunk_roll: i32,
+    // a new trunk needs more life than this
+    pub(crate) new_trunk_min_life: i32,
+    // a new trunk gets the current life +- this much
+    pub(crate) new_trunk_life_jitter: i32,
+    // steps between spawns, in multiples of the multiplier
+    pub(crate) shoot_cooldown: i32,
+}
+
+// the cbonsai rules with every probability read from a rules file instead of compiled in.
+// any table missing from the file keeps its cbonsai default
+#[derive(Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct TableRules {
+    pub(crate) trunk: TrunkTables,
+    pub(crate) shoot_left: BranchTables,
+    pub(crate) shoot_right: BranchTables,
+    pub(crate) dying: BranchTables,
+    pub(crate) dead: BranchTables,
+    pub(crate) blossom: BranchTables,
+    pub(crate) fruit: BranchTables,
+    pub(crate) root_left: BranchTables,
+    pub(crate) root_right: BranchTables,
+    pub(crate) spawn: SpawnTable,
+}
+
+impl Default for TrunkTables {
+    fn default() -> TrunkTables {
+        TrunkTables {
+            sapling_dx: DeltaTable::new(3, &[(-1, 1), (0, 1), (1, 1)]),
+            young_dx: DeltaTable::new(10, &[(-2, 1), (-1, 3), (0, 2), (1, 3), (2, 1)]),
+            young_dy: None,
+            mature_dx: DeltaTable::new(3, &[(-1, 1), (0, 1), (1, 1)]),
+            mature_dy: DeltaTable::new(10, &[(0, 3), (-1, 7)]),
+        }
+    }
+}
+
+impl Default for SpawnTable {
+    fn default() -> SpawnTable {
+        SpawnTable {
+            trunk_branch_roll: 3,
+            new_trunk_roll: 8,
+            new_trunk_min_life: 7,
+            new_trunk_life_jitter: 2,
+            shoot_cooldown: 2,
+        }
+    }
+}
+
+impl Default for TableRules {
+    fn default() -> TableRules {
+        TableRules {
+            trunk: TrunkTables::default(),
+            shoot_left: BranchTables {
+                dx: DeltaTable::new(10, &[(-2, 2), (-1, 4), (0, 3), (1, 1)]),
+                dy: DeltaTable::new(10, &[(-1, 2), (0, 6), (1, 2)]),
+            },
+            shoot_right: BranchTables {
+                dx: DeltaTable::new(10, &[(2, 2), (1, 4), (0, 3), (-1, 1)]),
+                dy: DeltaTable::new(10, &[(-1, 2), (0, 6), (1, 2)]),
+            },
+            dying: BranchTables {
+                dx: DeltaTable::new(15, &[(-3, 1), (-2, 2), (-1, 3), (0, 3), (1, 3), (2, 2), (3, 1)]),
+                dy: DeltaTable::new(10, &[(-1, 2), (0, 7), (1, 1)]),
+            },
+            dead: BranchTables {
+                dx: DeltaTable::new(3, &[(-1, 1), (0, 1), (1, 1)]),
+                dy: DeltaTable::new(10, &[(-1, 3), (0, 4), (1, 3)]),
+            },
+            blossom: BranchTables {
+                dx: DeltaTable::new(3, &[(-1, 1), (0, 1), (1, 1)]),
+                dy: DeltaTable::new(2, &[(-1, 1), (0, 1)]),
+            },
+            fruit: BranchTables {
+                dx: DeltaTable::new(3, &[(-1, 1), (0, 1), (1, 1)]),
+                dy: DeltaTable::new(1, &[(1, 1)]),
+            },
+            root_left: BranchTables {
+                dx: DeltaTable::new(10, &[(-1, 6), (-2, 3), (0, 1)]),
+                dy: DeltaTable::new(10, &[(0, 7), (1, 3)]),
+            },
+            root_right: BranchTables {
+                dx: DeltaTable::new(10, &[(1, 6), (2, 3), (0, 1)]),
+                dy: DeltaTable::new(10, &[(0, 7), (1, 3)]),
+            },
+            spawn: SpawnTable::default(),
+        }
+    }
+}
+
+impl TableRules {
+    // .json files are read as JSON, anything else as TOML
+    pub fn load(fname: &str) -> Result<TableRules, RulesError> {
+        let path = Path::new(fname);
+
+        let data = fs::read_to_string(path).map_err(|e| RulesError::Read { file: fname.to_string(), reason: e.to_string() })?;
+
+        let rules: TableRules = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&data).map_err(|e| RulesError::Parse { file: fname.to_string(), reason: e.to_string() })?
+        } else {
+            toml::from_str(&data).map_err(|e| RulesError::Parse { file: fname.to_string(), reason: e.to_string() })?
+        };
+
+        rules.validate(fname)?;
+        Ok(rules)
+    }
+
+    fn validate(&self, file: &str) -> Result<(), RulesError> {
+        self.trunk.sapling_dx.validate(file, "trunk.sapling_dx")?;
+        self.trunk.young_dx.validate(file, "trunk.young_dx")?;
+        if let Some(young_dy) = &self.trunk.young_dy {
+            young_dy.validate(file, "trunk.young_dy")?;
+        }
+        self.trunk.mature_dx.validate(file, "trunk.mature_dx")?;
+        self.trunk.mature_dy.validate(file, "trunk.mature_dy")?;
+        self.shoot_left.dx.validate(file, "shoot_left.dx")?;
+        self.shoot_left.dy.validate(file, "shoot_left.dy")?;
+        self.shoot_right.dx.validate(file, "shoot_right.dx")?;
+        self.shoot_right.dy.validate(file, "shoot_right.dy")?;
+        self.dying.dx.validate(file, "dying.dx")?;
+        self.dying.dy.validate(file, "dying.dy")?;
+        self.dead.dx.validate(file, "dead.dx")?;
+        self.dead.dy.validate(file, "dead.dy")?;
+        self.blossom.dx.validate(file, "blossom.dx")?;
+        self.blossom.dy.validate(file, "blossom.dy")?;
+        self.fruit.dx.validate(file, "fruit.dx")?;
+        self.fruit.dy.validate(file, "fruit.dy")?;
+        self.root_left.dx.validate(file, "root_left.dx")?;
+        self.root_left.dy.validate(file, "root_left.dy")?;
+        self.root_right.dx.validate(file, "root_right.dx")?;
+        self.root_right.dy.validate(file, "root_right.dy")?;
+
+        let spawn = &self.spawn;
+        let invalid = |reason: String| RulesError::Invalid { file: file.to_string(), table: "spawn", reason };
+
+        if spawn.trunk_branch_roll < 1 || spawn.new_trunk_roll < 1 {
+            return Err(invalid("trunk_branch_roll and new_trunk_roll must be at least 1".to_string()));
+        }
+        // life and the multiplier are at most 200 and 20, so the life of a new trunk and the cooldown can't overflow
+        if !(0..=MAX_SPAWN_STEPS).contains(&spawn.new_trunk_life_jitter) || !(0..=MAX_SPAWN_STEPS).contains(&spawn.shoot_cooldown) {
+            return Err(invalid(format!("new_trunk_life_jitter and shoot_cooldown must be between 0 and {}", MAX_SPAWN_STEPS)));
+        }
+
+        Ok(())
+    }
+}
+
+impl GrowthRules for TableRules {
+    fn deltas(&self, rng: &mut StdRng, branch: &BranchState) -> (i32, i32) {
+        let tables = match branch.branch_type {
+            BranchType::Trunk => {
+                let trunk = &self.trunk;
+
+                if branch.age <= 2 || branch.life < 4 {
+                    return (trunk.sapling_dx.sample(rng), 0);
+                } else if branch.age < branch.multiplier * 3 {
+                    let dy = match &trunk.young_dy {
+                        Some(young_dy) => young_dy.sample(rng),
+                        None => if branch.age % (branch.multiplier / 2).max(1) == 0 { -1 } else { 0 },
+                    };
+                    return (trunk.young_dx.sample(rng), dy);
+                }
+
+                let dy = trunk.mature_dy.sample(rng);
+                return (trunk.mature_dx.sample(rng), dy);
+            },
+            BranchType::ShootLeft => &self.shoot_left,
+            BranchType::ShootRight => &self.shoot_right,
+            BranchType::Dying => &self.dying,
+            BranchType::Dead => &self.dead,
+            BranchType::Blossom => &self.blossom,
+            BranchType::Fruit => &self.fruit,
+            BranchType::RootLeft => &self.root_left,
+            BranchType::RootRight => &self.root_right,
+        };
+
+        // dy is rolled first, same as cbonsai
+        let dy = tables.dy.sample(rng);
+        (tables.dx.sample(rng), dy)
+    }
+
+    fn decay(&self, branch: &BranchState) -> Option<BranchType> {
+        CbonsaiRules.decay(branch)
+    }
+
+    fn spawn(&self, rng: &mut StdRng, branch: &BranchState) -> Spawn {
+        let spawn = &self.spawn;
+        let life = branch.life;
+
+        if branch.branch_type == BranchType::Blossom || branch.branch_type == BranchType::Fruit || branch.branch_type.is_root() {
+            return Spawn::Nothing;
+        }
+
+        if (branch.branch_type == BranchType::Trunk && rng.gen_range(0..spawn.trunk_branch_roll) == 0) || (life % branch.multiplier.max(1) == 0) {
+            if rng.gen_range(0..spawn.new_trunk_roll) == 0 && life > spawn.new_trunk_min_life {
+                let jitter = spawn.new_trunk_life_jitter;
+                return Spawn::Trunk { life: life + rng.gen_range(0..jitter * 2 + 1) - jitter };
+            } else if branch.shoot_cooldown <= 0 {
+                return Spawn::Shoot { life: life + branch.multiplier };
+            }
+        }
+
+        bloom(rng, branch)
+    }
+
+    fn shoot_cooldown(&self, multiplier: i32) -> i32 {
+        multiplier * self.spawn.shoot_cooldown
+    }
+
+    fn style(&self, rng: &mut StdRng, branch_type: BranchType) -> Style {
+        CbonsaiRules.style(rng, branch_type)
+    }
+
+    fn glyphs(&self, rng: &mut StdRng, branch: &BranchState, dx: i32, dy: i32, leaves: &[char]) -> String {
+        CbonsaiRules.glyphs(rng, branch, dx, dy, leaves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(toml: &str) -> Result<(), RulesError> {
+        let rules: TableRules = toml::from_str(toml).unwrap();
+        rules.validate("test.toml")
+    }
+
+    fn invalid_table(toml: &str) -> &'static str {
+        match check(toml) {
+            Err(RulesError::Invalid { table, .. }) => table,
+            _ => panic!("{} should be invalid", toml),
+        }
+    }
+
+    #[test]
+    fn the_defaults_are_valid() {
+        assert!(check("").is_ok());
+        assert!(check("[dying]\ndx = { roll = 3, weights = [[-1, 1], [0, 1], [1, 1]] }\ndy = { roll = 1, weights = [[3, 1]] }").is_ok());
+    }
+
+    #[test]
+    fn weights_must_cover_the_roll() {
+        let short = "[blossom]\ndx = { roll = 3, weights = [[-1, 1], [0, 1]] }\ndy = { roll = 1, weights = [[0, 1]] }";
+        assert_eq!(invalid_table(short), "blossom.dx");
+        let over = "[blossom]\ndx = { roll = 3, weights = [[0, 3]] }\ndy = { roll = 1, weights = [[0, 2]] }";
+        assert_eq!(invalid_table(over), "blossom.dy");
+    }
+
+    #[test]
+    fn rolls_and_weights_must_be_positive() {
+        assert_eq!(invalid_table("[trunk]\nsapling_dx = { roll = 0, weights = [] }"), "trunk.sapling_dx");
+        assert_eq!(invalid_table("[trunk]\nmature_dy = { roll = 1, weights = [[0, 2], [1, -1]] }"), "trunk.mature_dy");
+        assert_eq!(invalid_table("[dead]\ndx = { roll = 1, weights = [[-2147483648, 1]] }\ndy = { roll = 1, weights = [[0, 1]] }"), "dead.dx");
+    }
+
+    #[test]
+    fn spawn_limits() {
+        assert_eq!(invalid_table("[spawn]\ntrunk_branch_roll = 0"), "spawn");
+        assert_eq!(invalid_table("[spawn]\nnew_trunk_roll = -1"), "spawn");
+        assert_eq!(invalid_table("[spawn]\nnew_trunk_life_jitter = -1"), "spawn");
+        assert_eq!(invalid_table("[spawn]\nshoot_cooldown = -2"), "spawn");
+        assert_eq!(invalid_table("[spawn]\nnew_trunk_life_jitter = 2000000000"), "spawn");
+        assert_eq!(invalid_table("[spawn]\nshoot_cooldown = 200000000"), "spawn");
+    }
+
+    #[test]
+    fn unknown_fields_dont_parse() {
+        assert!(toml::from_str::<TableRules>("[spawn]\nshoot_cooldwn = 2").is_err());
+    }
+
+    // the grown tree as glyphs and their colors, for comparing two sets of rules
+    fn grown(rules: &dyn GrowthRules, conf: &crate::Config, seed: u64) -> String {
+        let mut growth = crate::growth::Growth::seeded(conf, seed, crate::tree::Tree::new(40, 100), rules);
+        growth.by_ref().for_each(drop);
+
+        let tree = growth.tree();
+        let mut text = String::new();
+        for y in 0..tree.height() {
+            for x in 0..tree.width() {
+                if let Some(cell) = tree.get(y, x) {
+                    text.push_str(&format!("{},{} {} {} {}\n", y, x, cell.glyph, cell.style.color, cell.style.bold));
+                }
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn the_default_tables_grow_cbonsai() {
+        let example = TableRules::load(concat!(env!("CARGO_MANIFEST_DIR"), "/examples/cbonsai-rules.toml")).unwrap();
+
+        let mut conf = crate::Config { life_start: 40, ..crate::Config::default() };
+        crate::args::default_leaves(&mut conf);
+        let flowering = crate::Config { blossoms: 0.3, fruit: 0.2, ..conf.clone() };
+
+        for conf in [&conf, &flowering] {
+            for seed in [1, 2, 3, 99] {
+                let cbonsai = grown(&CbonsaiRules, conf, seed);
+                assert_eq!(grown(&TableRules::default(), conf, seed), cbonsai, "seed {}", seed);
+                assert_eq!(grown(&example, conf, seed), cbonsai, "seed {} with the example file", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn the_worst_spawn_table_still_finishes() {
+        let worst = "[spawn]\ntrunk_branch_roll = 1\nnew_trunk_roll = 1\nnew_trunk_min_life = 0\nnew_trunk_life_jitter = 100\nshoot_cooldown = 0";
+        let rules: TableRules = toml::from_str(worst).unwrap();
+        rules.validate("worst.toml").unwrap();
+
+        let mut conf = crate::Config { life_start: 60, ..crate::Config::default() };
+        crate::args::default_leaves(&mut conf);
+        let mut growth = crate::growth::Growth::seeded(&conf, 1, crate::tree::Tree::new(8, 24), &rules);
+        growth.by_ref().for_each(drop);
+        assert!(growth.is_finished());
+    }
+}
@@ -0,0 +1,221 @@
+use std::thread;
+use std::time::Duration;
+
+use crate::Config;
+use crate::growth::{Counters, Growth, GrowthEvent};
+use crate::render::{self, Renderer};
+use crate::rules::GrowthRules;
+use crate::tree::Tree;
+
+// splits cols into equal slots, one per pot, as many as were asked for but never more than fit
+// (the --trees help says so). --forest is as many as fit
+pub fn pot_slots(conf: &Config, cols: i32, base_width: i32) -> Vec<(i32, i32)> {
+    // a pot and a bit of room on either side, the bare tree of base 0 gets as much as the small pot
+    let spacing = base_width.max(15) + 4;
+    let fit = (cols / spacing).max(1);
+    let pots = if conf.trees == 0 { fit } else { conf.trees.min(fit) };
+
+    (0..pots).map(|i| {
+        let x = cols * i / pots;
+        (x, cols * (i + 1) / pots - x)
+    }).collect()
+}
+
+// life and multiplier are tuned for the tree window of an 80x24 terminal, shrink them to fit smaller ones
+pub fn scale_to_window(conf: &mut Config, max_y: i32, max_x: i32, life_start: i32, multiplier: i32) {
+    let scale = (max_y as f32 / 20.0).min(max_x as f32 / 80.0).min(1.0);
+    conf.life_start = ((life_start as f32 * scale).round() as i32).max(1);
+    conf.multiplier = ((multiplier as f32 * scale).round() as i32).max(1);
+}
+
+// an empty tree for the slot (x, width) of a screen rows high, standing on its pot (y, x, height, width on the screen)
+// if it has one, otherwise on row floor
+pub fn plant(rows: i32, (slot_x, slot_width): (i32, i32), pot: Option<(i32, i32, i32, i32)>, floor: i32, hangs_below_rim: bool) -> Tree {
+    let mut tree = Tree::new(rows, slot_width);
+
+    // branches stop at the rim, only a cascade may hang down beside the pot. roots grow into it
+    match pot {
+        Some((base_y, base_x, _, base_w)) => {
+            let (pot_start, pot_end) = (base_x - slot_x, base_x - slot_x + base_w);
+            if hangs_below_rim {
+                tree.set_ground(pot_start, pot_end, base_y);
+            } else {
+                tree.set_ground(0, slot_width, base_y);
+            }
+            // inside the end caps of the rim
+            tree.set_soil(pot_start + 1, pot_end - 1, base_y);
+        },
+        None => tree.set_ground(0, slot_width, floor),
+    }
+
+    tree
+}
+
+// --auto-scale for a grid of rows x cols, the tree gets the rows above the pot unless it hangs down beside it
+pub fn fit_to_grid(conf: &mut Config, rows: i32, cols: i32) {
+    let (life_start, multiplier) = (conf.life_start, conf.multiplier);
+    let hangs_below_rim = conf.style.is_some_and(|s| s.hangs_below_rim());
+    let tree_rows = if hangs_below_rim { rows } else { rows - render::base_size(conf.base_type).1 };
+    scale_to_window(conf, tree_rows, cols, life_start, multiplier);
+}
+
+// a growth for every slot, planted in its pot (or on row floor without one) on a screen rows high. the first tree
+// grows from the seed itself, the others from seed+1, seed+2 and so on
+pub fn plant_forest<'a>(conf: &'a Config, rules: &'a dyn GrowthRules, rows: i32, slots: &[(i32, i32)], pots: &[Option<(i32, i32, i32, i32)>], floor: i32) -> Vec<Growth<'a>> {
+    let hangs_below_rim = conf.style.is_some_and(|s| s.hangs_below_rim());
+
+    slots.iter().zip(pots).enumerate().map(|(i, (&slot, &pot))| {
+        let seed = (conf.seed as u64).wrapping_add(i as u64);
+        Growth::seeded(conf, seed, plant(rows, slot, pot, floor, hangs_below_rim), rules)
+    }).collect()
+}
+
+// the trees take turns, one step each, and hand what they did to notify with their index. false once they've
+// all finished, or together grew as many branches as conf.branch_limit allows
+pub fn grow_step(conf: &Config, growths: &mut [Growth], mut notify: impl FnMut(usize, &mut Growth)) -> bool {
+    let mut growing = false;
+
+    for (i, growth) in growths.iter_mut().enumerate() {
+        // a tree that just finished still has its GrowthFinished to hand out
+        growing |= growth.next().is_some();
+        notify(i, growth);
+    }
+
+    // a --persist tree only grows as far as the time since the last run allows
+    growing && !(conf.branch_limit > 0 && total_counters(growths).branches >= conf.branch_limit)
+}
+
+// --backend=ansi and plain, and --headless: the trees grow in a grid the size of the renderer instead of ncurses
+// windows, with the pots and message drawn around them the same way. nothing reads keys, so it's one tree and done
+pub fn grow_in_memory(conf: &Config, rules: &dyn GrowthRules, renderer: &mut dyn Renderer, animate: bool) -> Counters {
+    let (rows, cols) = renderer.size();
+    let (base_width, base_height) = render::base_size(conf.base_type);
+    let slots = pot_slots(conf, cols, base_width);
+    let pots = pot_rects(conf, rows, &slots);
+    let mut growths = plant_forest(conf, rules, rows, &slots, &pots, rows - base_height);
+
+    if animate {
+        for &(y, x, _, _) in pots.iter().flatten() {
+            render::draw_base(renderer, y, x, conf.base_type);
+        }
+    }
+
+    while grow_step(conf, &mut growths, |i, growth| {
+        let slot_x = slots[i].0;
+        for event in growth.drain_events() {
+            // only roots show in front of the pot
+            if let GrowthEvent::CellPlaced { y, x, glyph, branch_type, style } = event {
+                if animate && (branch_type.is_root() || !pot_contains(&pots, y, slot_x + x)) {
+                    renderer.set_style(style);
+                    renderer.put_cell(y, slot_x + x, glyph);
+                }
+            }
+        }
+    }) {
+        if animate && !(conf.load && total_counters(&growths).branches < conf.target_branch_count) {
+            renderer.flush();
+            thread::sleep(Duration::from_secs_f32(conf.time_step));
+        }
+    }
+
+    let trees: Vec<_> = growths.iter().zip(&slots).map(|(growth, &(slot_x, _))| (growth.tree(), slot_x)).collect();
+    draw_scene(renderer, conf, &trees, &pots);
+    renderer.flush();
+
+    total_counters(&growths)
+}
+
+// each pot centered at the bottom of its slot, (y, x, height, width) like draw_wins places the base windows
+pub fn pot_rects(conf: &Config, rows: i32, slots: &[(i32, i32)]) -> Vec<Option<(i32, i32, i32, i32)>> {
+    let (base_width, base_height) = render::base_size(conf.base_type);
+
+    slots.iter().map(|&(slot_x, slot_width)| {
+        (base_width > 0 && base_height > 0).then_some((rows - base_height, slot_x + slot_width / 2 - base_width / 2, base_height, base_width))
+    }).collect()
+}
+
+// whether (y, x) on the screen is in one of the pots pot_rects placed, the blank inside of a pot too
+pub fn pot_contains(pots: &[Option<(i32, i32, i32, i32)>], y: i32, x: i32) -> bool {
+    pots.iter().flatten().any(|&(pot_y, pot_x, height, width)| y >= pot_y && y < pot_y + height && x >= pot_x && x < pot_x + width)
+}
+
+// the finished picture as ncurses shows it: the trees (each at its x), the pots over them and the roots over the pots,
+// then the message on top
+pub fn draw_scene(renderer: &mut dyn Renderer, conf: &Config, trees: &[(&Tree, i32)], pots: &[Option<(i32, i32, i32, i32)>]) {
+    for roots in [false, true] {
+        for &(tree, slot_x) in trees {
+            for y in 0..tree.height() {
+                for x in 0..tree.width() {
+                    if let Some(cell) = tree.get(y, x).filter(|c| c.branch_type.is_root() == roots) {
+                        renderer.set_style(cell.style);
+                        renderer.put_cell(y, slot_x + x, cell.glyph);
+                    }
+                }
+            }
+        }
+
+        if !roots {
+            for &(y, x, height, width) in pots.iter().flatten() {
+                renderer.set_style(render::PLAIN);
+                for row in y..y + height {
+                    render::put_str(renderer, row, x, &" ".repeat(width as usize));
+                }
+                render::draw_base(renderer, y, x, conf.base_type);
+            }
+        }
+    }
+    render::draw_message(renderer, &conf.message);
+}
+
+// the branch counts of every tree together, that's what gets saved and replayed
+pub fn total_counters(growths: &[Growth]) -> Counters {
+    let mut total = *growths[0].counters();
+    for growth in &growths[1..] {
+        total.branches = total.branches.saturating_add(growth.counters().branches);
+        total.shoots = total.shoots.saturating_add(growth.counters().shoots);
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlap((a_y, a_x, a_h, a_w): (i32, i32, i32, i32), (b_y, b_x, b_h, b_w): (i32, i32, i32, i32)) -> bool {
+        a_y < b_y + b_h && b_y < a_y + a_h && a_x < b_x + b_w && b_x < a_x + a_w
+    }
+
+    #[test]
+    fn every_tree_gets_its_own_pot() {
+        let (base_width, _) = render::base_size(1);
+        for (trees, cols, pots) in [(3, 150, 3), (4, 150, 4), (10, 80, 2), (5, 40, 1), (0, 150, 4)] {
+            let conf = Config { trees, ..Config::default() };
+            let rects: Vec<_> = pot_rects(&conf, 24, &pot_slots(&conf, cols, base_width)).into_iter().flatten().collect();
+            assert_eq!(rects.len(), pots, "--trees={} in {} columns", trees, cols);
+
+            for (i, &rect) in rects.iter().enumerate() {
+                assert!(rect.1 >= 0 && rect.1 + rect.3 <= cols);
+                assert!(rects[i + 1..].iter().all(|&other| !overlap(rect, other)));
+            }
+        }
+    }
+
+    #[test]
+    fn each_tree_grows_from_its_own_seed() {
+        let grow = |trees: i32, seed: i32, width: i32| {
+            let conf = Config { trees, seed, width, ..Config::default() };
+            crate::grow_headless(&conf).unwrap().0
+        };
+        let forest = grow(3, 42, 150);
+
+        // each slot is 50 wide, the same picture as one tree grown alone from seed+i
+        for i in 0..3 {
+            let alone = grow(1, 42 + i, 50);
+            for y in 0..24 {
+                for x in 0..50 {
+                    assert_eq!(forest.cell(y, i * 50 + x).0, alone.cell(y, x).0, "tree {} at ({}, {})", i, y, x);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::tree::{Cell, Style, Tree};
+
+// a year is a phase from 0.0 to 1.0, a quarter each: summer, autumn, winter, spring
+#[derive(PartialEq, Clone, Copy)]
+pub enum Season {
+    Summer,
+    Autumn,
+    Winter,
+    Spring,
+}
+
+impl Season {
+    // the season at phase, and how far into it we are (0.0 to 1.0)
+    pub fn at(phase: f32) -> (Season, f32) {
+        let phase = phase.rem_euclid(1.0) * 4.0;
+        let season = match phase as i32 {
+            0 => Season::Summer,
+            1 => Season::Autumn,
+            2 => Season::Winter,
+            _ => Season::Spring,
+        };
+        (season, phase.fract())
+    }
+}
+
+struct Leaf {
+    y: i32,
+    x: i32,
+    cell: Cell,
+    // when in a season this leaf turns, falls and regrows, so they don't all go at once
+    threshold: f32,
+    // where it is once it has fallen off, and whether it lies on the ground yet
+    fallen: Option<(i32, i32, bool)>,
+}
+
+// a finished tree going through the seasons. the wood stays, the leaves change
+pub struct Seasons {
+    bare: Tree,
+    leaves: Vec<Leaf>,
+    rng: StdRng,
+    settled: bool,
+}
+
+impl Seasons {
+    pub fn new(tree: &Tree, seed: u64) -> Seasons {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut bare = tree.clone();
+        let mut leaves = Vec::new();
+
+        for y in 0..tree.height() {
+            for x in 0..tree.width() {
+                if let Some(cell) = tree.get(y, x).filter(|c| c.leaf) {
+                    leaves.push(Leaf { y, x, cell: *cell, threshold: rng.gen(), fallen: None });
+                    bare.set(y, x, None);
+                }
+            }
+        }
+
+        Seasons { bare, leaves, rng, settled: false }
+    }
+
+    // moves everything on to phase, falling leaves drop one row per call
+    pub fn update(&mut self, phase: f32) {
+        let (season, progress) = Season::at(phase);
+
+        for leaf in self.leaves.iter_mut() {
+            let dropped = match season {
+                Season::Summer => false,
+                Season::Autumn => progress > 0.4 + leaf.threshold * 0.6,
+                Season::Winter => true,
+                // last year's leaves are gone by spring
+                Season::Spring => false,
+            };
+
+            if !dropped {
+                leaf.fallen = None;
+                continue;
+            }
+
+            let (y, x, landed) = leaf.fallen.unwrap_or((leaf.y, leaf.x, false));
+            let ground = self.bare.ground(x) - 1;
+
+            // starting partway through the year, whatever already fell just lies there
+            leaf.fallen = if !self.settled {
+                Some((ground, x, true))
+            } else if landed || y >= ground {
+                Some((y.min(ground), x, true))
+            } else {
+                let x = (x + self.rng.gen_range(-1..=1)).clamp(0, (self.bare.width() - 1).max(0));
+                Some(((y + 1).min(self.bare.ground(x) - 1), x, false))
+            };
+        }
+
+        self.settled = true;
+    }
+
+    // the tree as it looks at phase: the wood, the leaves still on it, and the ones that fell
+    pub fn view(&self, phase: f32) -> Tree {
+        let (season, progress) = Season::at(phase);
+        let mut view = self.bare.clone();
+
+        for leaf in &self.leaves {
+            if let Some((y, x, _)) = leaf.fallen {
+                view.set(y, x, Some(Cell { style: autumn_style(leaf, 1.0), ..leaf.cell }));
+                continue;
+            }
+
+            let style = match season {
+                Season::Summer => Some(leaf.cell.style),
+                Season::Autumn => Some(autumn_style(leaf, progress)),
+                Season::Winter => None,
+                // leaves bud light green and darken over summer
+                Season::Spring if progress > leaf.threshold => Some(Style { color: 10, bold: false }),
+                Season::Spring => None,
+            };
+
+            if let Some(style) = style {
+                view.set(leaf.y, leaf.x, Some(Cell { style, ..leaf.cell }));
+            }
+        }
+
+        view
+    }
+}
+
+// green turns yellow, then red or orange
+fn autumn_style(leaf: &Leaf, progress: f32) -> Style {
+    let turn = leaf.threshold * 0.6;
+
+    if progress > turn + 0.2 {
+        if leaf.threshold < 0.5 { Style { color: 9, bold: true } } else { Style { color: 3, bold: false } }
+    } else if progress > turn {
+        Style { color: 11, bold: true }
+    } else {
+        leaf.cell.style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+    use crate::args;
+    use crate::growth::Growth;
+    use crate::render::TextRenderer;
+    use crate::rules::CbonsaiRules;
+    use crate::scene::{draw_scene, plant, pot_rects};
+
+    #[test]
+    fn winter_prints_no_leaves_on_the_tree() {
+        let mut conf = Config { seed: 5, ..Config::default() };
+        args::default_leaves(&mut conf);
+        let pots = pot_rects(&conf, 24, &[(0, 80)]);
+        let mut growth = Growth::seeded(&conf, 5, plant(24, (0, 80), pots[0], 24, false), &CbonsaiRules);
+        growth.by_ref().for_each(drop);
+        let tree = growth.tree();
+
+        // the screen -p prints halfway through winter, as show_seasons leaves it
+        let mut seasons = Seasons::new(tree, 5);
+        seasons.update(0.625);
+        let view = seasons.view(0.625);
+        let mut screen = TextRenderer::new(24, 80, false);
+        draw_scene(&mut screen, &conf, &[(&view, 0)], &pots);
+
+        let on_tree = |y: i32, x: i32| tree.get(y, x).is_some_and(|c| c.leaf);
+        assert!((0..24).any(|y| (0..80).any(|x| on_tree(y, x))));
+        for y in 0..24 {
+            for x in 0..80 {
+                // whatever fell lies on the ground, nothing is left up in the tree
+                if screen.cell(y, x).0 == '&' {
+                    assert_eq!(y, tree.ground(x) - 1, "a leaf at ({}, {})", y, x);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::rules::{choose_color, choose_string, BranchState, GrowthRules, Spawn};
+use crate::rules_file::{BranchTables, DeltaTable, SpawnTable, TableRules, TrunkTables};
+use crate::tree::{BranchType, Style};
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Species {
+    Pine,
+    Maple,
+    Willow,
+    Cherry,
+    Juniper,
+}
+
+pub const SPECIES_NAMES: &[(&str, Species)] = &[
+    ("pine", Species::Pine),
+    ("maple", Species::Maple),
+    ("willow", Species::Willow),
+    ("cherry", Species::Cherry),
+    ("juniper", Species::Juniper),
+];
+
+// how a species is drawn, the strings are picked the same way choose_string picks cbonsai's
+pub struct Look {
+    // dy == 0, dx < 0, dx == 0, dx > 0
+    trunk: [&'static str; 4],
+    // dy > 0, dy == 0, dx < 0, dx == 0, dx > 0
+    shoot_left: [&'static str; 5],
+    shoot_right: [&'static str; 5],
+    // (bold half of the time, plain the other half)
+    wood: (i16, i16),
+    leaf: i16,
+    dead_leaf: i16,
+}
+
+const CBONSAI_TRUNK: [&str; 4] = ["/~", "\\|", "/|\\", "|/"];
+const CBONSAI_SHOOT_LEFT: [&str; 5] = ["\\", "\\_", "\\|", "/|", "/"];
+const CBONSAI_SHOOT_RIGHT: [&str; 5] = ["/", "_/", "\\|", "/|", "/"];
+
+impl Species {
+    pub fn from_name(name: &str) -> Option<Species> {
+        let name = name.trim().to_lowercase();
+        SPECIES_NAMES.iter().find(|(n, _)| *n == name).map(|(_, s)| *s)
+    }
+
+    // comma separated like --leaf, only used when no --leaf is given
+    pub fn leaves(&self) -> &'static str {
+        match self {
+            Species::Pine => "^,*",
+            Species::Maple => "&,*",
+            Species::Willow => "',;,|",
+            Species::Cherry => "*,@,&",
+            Species::Juniper => "%,#,&",
+        }
+    }
+
+    pub fn look(&self) -> Look {
+        match self {
+            // a thin straight trunk, needles in dark green
+            Species::Pine => Look {
+                trunk: ["/~", "\\|", "||", "|/"],
+                shoot_left: ["\\", "__", "\\|", "/|", "/"],
+                shoot_right: ["/", "__", "\\|", "/|", "/"],
+                wood: (11, 3),
+                leaf: 2,
+                dead_leaf: 2,
+            },
+            // red autumn leaves
+            Species::Maple => Look {
+                trunk: CBONSAI_TRUNK,
+                shoot_left: CBONSAI_SHOOT_LEFT,
+                shoot_right: CBONSAI_SHOOT_RIGHT,
+                wood: (11, 3),
+                leaf: 1,
+                dead_leaf: 9,
+            },
+            // hanging shoots lean the way they fall
+            Species::Willow => Look {
+                trunk: CBONSAI_TRUNK,
+                shoot_left: ["/", "\\_", "\\|", "/|", "/"],
+                shoot_right: ["\\", "_/", "\\|", "/|", "/"],
+                wood: (11, 3),
+                leaf: 10,
+                dead_leaf: 2,
+            },
+            // dark bark, pink blossoms (with --blossoms)
+            Species::Cherry => Look {
+                trunk: CBONSAI_TRUNK,
+                shoot_left: CBONSAI_SHOOT_LEFT,
+                shoot_right: CBONSAI_SHOOT_RIGHT,
+                wood: (1, 3),
+                leaf: 13,
+                dead_leaf: 5,
+            },
+            // twisted trunk with silvery deadwood
+            Species::Juniper => Look {
+                trunk: ["~/~", "\\~", "/~\\", "~/"],
+                shoot_left: ["\\", "~_", "\\~", "/~", "/"],
+                shoot_right: ["/", "_~", "~\\", "~|", "/"],
+                wood: (15, 3),
+                leaf: 2,
+                dead_leaf: 6,
+            },
+        }
+    }
+
+    pub fn rules(&self) -> TableRules {
+        let cbonsai = TableRules::default();
+
+        match self {
+            // straight up, level tiers of shoots that keep their needles flat
+            Species::Pine => TableRules {
+                trunk: TrunkTables {
+                    sapling_dx: DeltaTable::new(5, &[(-1, 1), (0, 3), (1, 1)]),
+                    young_dx: DeltaTable::new(10, &[(-1, 1), (0, 8), (1, 1)]),
+                    mature_dx: DeltaTable::new(10, &[(-1, 1), (0, 8), (1, 1)]),
+                    mature_dy: DeltaTable::new(10, &[(0, 2), (-1, 8)]),
+                    ..cbonsai.trunk
+                },
+                shoot_left: BranchTables {
+                    dx: DeltaTable::new(10, &[(-2, 4), (-1, 5), (0, 1)]),
+                    dy: DeltaTable::new(10, &[(-1, 1), (0, 8), (1, 1)]),
+                },
+                shoot_right: BranchTables {
+                    dx: DeltaTable::new(10, &[(2, 4), (1, 5), (0, 1)]),
+                    dy: DeltaTable::new(10, &[(-1, 1), (0, 8), (1, 1)]),
+                },
+                dying: BranchTables {
+                    dy: DeltaTable::new(10, &[(-1, 1), (0, 8), (1, 1)]),
+                    ..cbonsai.dying
+                },
+                spawn: SpawnTable {
+                    trunk_branch_roll: 4,
+                    new_trunk_roll: 30,
+                    ..cbonsai.spawn
+                },
+                ..cbonsai
+            },
+            // a wide, round crown
+            Species::Maple => TableRules {
+                dying: BranchTables {
+                    dx: DeltaTable::new(15, &[(-3, 2), (-2, 2), (-1, 3), (0, 1), (1, 3), (2, 2), (3, 2)]),
+                    ..cbonsai.dying
+                },
+                ..cbonsai
+            },
+            // shoots and leaves droop
+            Species::Willow => TableRules {
+                shoot_left: BranchTables {
+                    dy: DeltaTable::new(10, &[(0, 3), (1, 7)]),
+                    ..cbonsai.shoot_left
+                },
+                shoot_right: BranchTables {
+                    dy: DeltaTable::new(10, &[(0, 3), (1, 7)]),
+                    ..cbonsai.shoot_right
+                },
+                dying: BranchTables {
+                    dx: DeltaTable::new(10, &[(-1, 2), (0, 6), (1, 2)]),
+                    dy: DeltaTable::new(10, &[(0, 3), (1, 7)]),
+                },
+                dead: BranchTables {
+                    dy: DeltaTable::new(10, &[(0, 4), (1, 6)]),
+                    ..cbonsai.dead
+                },
+                ..cbonsai
+            },
+            // spreading shoots reaching up. the blossoms are only the look, they still need --blossoms
+            Species::Cherry => TableRules {
+                shoot_left: BranchTables {
+                    dy: DeltaTable::new(10, &[(-1, 4), (0, 5), (1, 1)]),
+                    ..cbonsai.shoot_left
+                },
+                shoot_right: BranchTables {
+                    dy: DeltaTable::new(10, &[(-1, 4), (0, 5), (1, 1)]),
+                    ..cbonsai.shoot_right
+                },
+                ..cbonsai
+            },
+            // a trunk that twists back and forth
+            Species::Juniper => TableRules {
+                trunk: TrunkTables {
+                    young_dx: DeltaTable::new(10, &[(-2, 3), (-1, 1), (0, 2), (1, 1), (2, 3)]),
+                    mature_dx: DeltaTable::new(10, &[(-2, 2), (-1, 2), (0, 2), (1, 2), (2, 2)]),
+                    mature_dy: DeltaTable::new(10, &[(0, 5), (-1, 5)]),
+                    ..cbonsai.trunk
+                },
+                ..cbonsai
+            },
+        }
+    }
+}
+
+impl fmt::Display for Species {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = SPECIES_NAMES.iter().find(|(_, s)| s == self).map(|(n, _)| *n).unwrap_or("unknown");
+        write!(f, "{}", name)
+    }
+}
+
+// grows by some other rules, but draws like the species
+pub struct SpeciesRules<'a> {
+    pub growth: &'a dyn GrowthRules,
+    pub look: Look,
+}
+
+impl GrowthRules for SpeciesRules<'_> {
+    fn deltas(&self, rng: &mut StdRng, branch: &BranchState) -> (i32, i32) {
+        self.growth.deltas(rng, branch)
+    }
+
+    fn decay(&self, branch: &BranchState) -> Option<BranchType> {
+        self.growth.decay(branch)
+    }
+
+    fn spawn(&self, rng: &mut StdRng, branch: &BranchState) -> Spawn {
+        self.growth.spawn(rng, branch)
+    }
+
+    fn shoot_cooldown(&self, multiplier: i32) -> i32 {
+        self.growth.shoot_cooldown(multiplier)
+    }
+
+    // same dice as choose_color, different paint
+    fn style(&self, rng: &mut StdRng, branch_type: BranchType) -> Style {
+        let look = &self.look;
+
+        match branch_type {
+            BranchType::Trunk | BranchType::ShootLeft | BranchType::ShootRight | BranchType::RootLeft | BranchType::RootRight => {
+                if rng.gen_range(0..2) == 0 {
+                    Style { color: look.wood.0, bold: true }
+                } else {
+                    Style { color: look.wood.1, bold: false }
+                }
+            },
+            BranchType::Dying => Style { color: look.leaf, bold: rng.gen_range(0..10) == 0 },
+            BranchType::Dead => Style { color: look.dead_leaf, bold: rng.gen_range(0..3) == 0 },
+            BranchType::Blossom | BranchType::Fruit => choose_color(rng, branch_type),
+        }
+    }
+
+    fn glyphs(&self, rng: &mut StdRng, branch: &BranchState, dx: i32, dy: i32, leaves: &[char]) -> String {
+        let look = &self.look;
+
+        // blossoms, fruit and roots look the same on every species
+        if matches!(branch.branch_type, BranchType::Blossom | BranchType::Fruit) || branch.branch_type.is_root() {
+            return choose_string(leaves, rng, branch.branch_type, branch.life, dx, dy);
+        }
+
+        // nearly dead branches are drawn as leaves, same as choose_string
+        let shoot = if branch.life < 4 {
+            None
+        } else {
+            match branch.branch_type {
+                BranchType::Trunk => {
+                    let i = if dy == 0 { 0 } else if dx < 0 { 1 } else if dx == 0 { 2 } else { 3 };
+                    return look.trunk[i].to_string();
+                },
+                BranchType::ShootLeft => Some(&look.shoot_left),
+                BranchType::ShootRight => Some(&look.shoot_right),
+                _ => None,
+            }
+        };
+
+        match shoot {
+            Some(strings) => {
+                let i = if dy > 0 { 0 } else if dy == 0 { 1 } else if dx < 0 { 2 } else if dx == 0 { 3 } else { 4 };
+                strings[i].to_string()
+            },
+            None => choose_string(leaves, rng, BranchType::Dying, branch.life, dx, dy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+    use crate::growth::Growth;
+    use crate::tree::{Cell, Tree};
+
+    fn grow(species: Species, seed: u64) -> Tree {
+        let mut conf = Config { species: Some(species), ..Config::default() };
+        crate::args::default_leaves(&mut conf);
+        crate::with_rules(&conf, |rules| {
+            let mut growth = Growth::seeded(&conf, seed, Tree::new(30, 80), rules);
+            growth.by_ref().for_each(drop);
+            growth.into_tree()
+        }).unwrap()
+    }
+
+    fn cells(tree: &Tree) -> impl Iterator<Item = &Cell> {
+        (0..tree.height()).flat_map(move |y| (0..tree.width()).filter_map(move |x| tree.get(y, x)))
+    }
+
+    #[test]
+    fn each_species_has_its_own_leaves_and_colors() {
+        for &(name, species) in SPECIES_NAMES {
+            let (tree, look) = (grow(species, 9), species.look());
+            assert!(cells(&tree).any(|c| c.leaf));
+
+            for cell in cells(&tree) {
+                if cell.leaf {
+                    assert!(species.leaves().split(',').any(|l| l.starts_with(cell.glyph)), "{} grew a {} leaf", name, cell.glyph);
+                }
+                let colors = match cell.branch_type {
+                    BranchType::Dying | BranchType::Dead => [look.leaf, look.dead_leaf],
+                    _ => [look.wood.0, look.wood.1],
+                };
+                assert!(colors.contains(&cell.style.color), "{} drew a {} in color {}", name, cell.branch_type, cell.style.color);
+            }
+        }
+    }
+
+    #[test]
+    fn species_grow_differently_from_one_seed() {
+        let glyphs = |tree: &Tree| cells(tree).map(|c| c.glyph).collect::<String>();
+        let trees: Vec<String> = SPECIES_NAMES.iter().map(|&(_, species)| glyphs(&grow(species, 9))).collect();
+        for (i, tree) in trees.iter().enumerate() {
+            assert!(trees[i + 1..].iter().all(|other| other != tree), "{} grows like another species", SPECIES_NAMES[i].0);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::fmt;
+
+use crate::rules_file::{BranchTables, DeltaTable, SpawnTable, TableRules, TrunkTables};
+
+// the classic bonsai shapes, each one is just a set of rules tables leaning the dice a certain way
+#[derive(PartialEq, Clone, Copy)]
+pub enum BonsaiStyle {
+    FormalUpright,
+    InformalUpright,
+    Cascade,
+    Windswept,
+    Literati,
+}
+
+pub const STYLE_NAMES: &[(&str, BonsaiStyle)] = &[
+    ("formal-upright", BonsaiStyle::FormalUpright),
+    ("informal-upright", BonsaiStyle::InformalUpright),
+    ("cascade", BonsaiStyle::Cascade),
+    ("windswept", BonsaiStyle::Windswept),
+    ("literati", BonsaiStyle::Literati),
+];
+
+impl BonsaiStyle {
+    // accepts "formal-upright", "formal_upright" and "formal upright"
+    pub fn from_name(name: &str) -> Option<BonsaiStyle> {
+        let name = name.trim().to_lowercase().replace(['_', ' '], "-");
+        STYLE_NAMES.iter().find(|(n, _)| *n == name).map(|(_, s)| *s)
+    }
+
+    // a cascade grows out over the rim and hangs down beside the pot
+    pub fn hangs_below_rim(&self) -> bool {
+        *self == BonsaiStyle::Cascade
+    }
+
+    pub fn rules(&self) -> TableRules {
+        let cbonsai = TableRules::default();
+
+        match self {
+            // dead straight trunk rising fast, shoots reaching out level on both sides
+            BonsaiStyle::FormalUpright => TableRules {
+                trunk: TrunkTables {
+                    sapling_dx: DeltaTable::new(5, &[(-1, 1), (0, 3), (1, 1)]),
+                    young_dx: DeltaTable::new(10, &[(-1, 1), (0, 8), (1, 1)]),
+                    young_dy: Some(DeltaTable::new(10, &[(-1, 7), (0, 3)])),
+                    mature_dx: DeltaTable::new(10, &[(-1, 1), (0, 8), (1, 1)]),
+                    mature_dy: DeltaTable::new(10, &[(0, 1), (-1, 9)]),
+                },
+                shoot_left: BranchTables {
+                    dx: DeltaTable::new(10, &[(-2, 3), (-1, 5), (0, 2)]),
+                    dy: DeltaTable::new(10, &[(-1, 1), (0, 7), (1, 2)]),
+                },
+                shoot_right: BranchTables {
+                    dx: DeltaTable::new(10, &[(2, 3), (1, 5), (0, 2)]),
+                    dy: DeltaTable::new(10, &[(-1, 1), (0, 7), (1, 2)]),
+                },
+                ..cbonsai
+            },
+            // the trunk bends one way while young and back the other way when mature
+            BonsaiStyle::InformalUpright => TableRules {
+                trunk: TrunkTables {
+                    young_dx: DeltaTable::new(10, &[(-2, 2), (-1, 5), (0, 2), (1, 1)]),
+                    mature_dx: DeltaTable::new(10, &[(-1, 1), (0, 3), (1, 5), (2, 1)]),
+                    ..cbonsai.trunk
+                },
+                ..cbonsai
+            },
+            // over the rim to the right, then down past the pot, shoots and leaves droop
+            BonsaiStyle::Cascade => TableRules {
+                trunk: TrunkTables {
+                    sapling_dx: DeltaTable::new(3, &[(1, 2), (2, 1)]),
+                    young_dx: DeltaTable::new(10, &[(1, 4), (2, 6)]),
+                    young_dy: Some(DeltaTable::new(10, &[(-1, 3), (0, 7)])),
+                    mature_dx: DeltaTable::new(10, &[(0, 3), (1, 5), (2, 2)]),
+                    mature_dy: DeltaTable::new(10, &[(0, 2), (1, 8)]),
+                },
+                shoot_left: BranchTables {
+                    dx: DeltaTable::new(10, &[(-1, 4), (0, 4), (1, 2)]),
+                    dy: DeltaTable::new(10, &[(0, 5), (1, 5)]),
+                },
+                shoot_right: BranchTables {
+                    dx: DeltaTable::new(10, &[(2, 3), (1, 5), (0, 2)]),
+                    dy: DeltaTable::new(10, &[(0, 5), (1, 5)]),
+                },
+                dying: BranchTables {
+                    dy: DeltaTable::new(10, &[(-1, 1), (0, 5), (1, 4)]),
+                    ..cbonsai.dying
+                },
+                ..cbonsai
+            },
+            // everything is blown to the right, the left side only gets stubs
+            BonsaiStyle::Windswept => TableRules {
+                trunk: TrunkTables {
+                    young_dx: DeltaTable::new(10, &[(0, 2), (1, 5), (2, 3)]),
+                    mature_dx: DeltaTable::new(10, &[(0, 3), (1, 5), (2, 2)]),
+                    mature_dy: DeltaTable::new(10, &[(0, 5), (-1, 5)]),
+                    ..cbonsai.trunk
+                },
+                shoot_left: BranchTables {
+                    dx: DeltaTable::new(10, &[(-1, 2), (0, 3), (1, 5)]),
+                    dy: DeltaTable::new(10, &[(-1, 2), (0, 7), (1, 1)]),
+                },
+                shoot_right: BranchTables {
+                    dx: DeltaTable::new(10, &[(3, 1), (2, 5), (1, 4)]),
+                    dy: DeltaTable::new(10, &[(-1, 2), (0, 7), (1, 1)]),
+                },
+                dying: BranchTables {
+                    dx: DeltaTable::new(15, &[(-1, 2), (0, 3), (1, 4), (2, 4), (3, 2)]),
+                    ..cbonsai.dying
+                },
+                ..cbonsai
+            },
+            // a tall, thin, wandering trunk with few shoots and a small crown at the top
+            BonsaiStyle::Literati => TableRules {
+                trunk: TrunkTables {
+                    young_dx: DeltaTable::new(10, &[(-1, 3), (0, 4), (1, 3)]),
+                    young_dy: Some(DeltaTable::new(10, &[(-1, 9), (0, 1)])),
+                    mature_dx: DeltaTable::new(10, &[(-1, 3), (0, 4), (1, 3)]),
+                    mature_dy: DeltaTable::new(10, &[(0, 1), (-1, 9)]),
+                    ..cbonsai.trunk
+                },
+                dying: BranchTables {
+                    dy: DeltaTable::new(10, &[(-1, 4), (0, 6)]),
+                    ..cbonsai.dying
+                },
+                spawn: SpawnTable {
+                    trunk_branch_roll: 12,
+                    new_trunk_roll: 50,
+                    shoot_cooldown: 4,
+                    ..cbonsai.spawn
+                },
+                ..cbonsai
+            },
+        }
+    }
+}
+
+impl fmt::Display for BonsaiStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = STYLE_NAMES.iter().find(|(_, s)| s == self).map(|(n, _)| *n).unwrap_or("unknown");
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+    use crate::render::TextRenderer;
+    use crate::scene::{pot_contains, pot_rects};
+
+    fn grow(style: BonsaiStyle, seed: i32) -> TextRenderer {
+        crate::grow_headless(&Config { style: Some(style), seed, ..Config::default() }).unwrap().0
+    }
+
+    // every cell of the 80x24 screen with something on it that isn't the pot
+    fn tree_cells(screen: &TextRenderer) -> Vec<(i32, i32)> {
+        let pots = pot_rects(&Config::default(), 24, &[(0, 80)]);
+        (0..24).flat_map(|y| (0..80).map(move |x| (y, x)))
+            .filter(|&(y, x)| screen.cell(y, x).0 != ' ' && !pot_contains(&pots, y, x))
+            .collect()
+    }
+
+    #[test]
+    fn every_style_grows_its_own_shape() {
+        let screens: Vec<String> = STYLE_NAMES.iter().map(|&(_, style)| grow(style, 42).text()).collect();
+        for (i, screen) in screens.iter().enumerate() {
+            assert!(screens[i + 1..].iter().all(|other| other != screen), "{} grows like another style", STYLE_NAMES[i].0);
+        }
+    }
+
+    #[test]
+    fn a_cascade_hangs_below_the_rim() {
+        let rim = pot_rects(&Config::default(), 24, &[(0, 80)])[0].unwrap().0;
+        for seed in 1..6 {
+            assert!(tree_cells(&grow(BonsaiStyle::Cascade, seed)).iter().any(|&(y, _)| y > rim), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn windswept_grows_to_one_side() {
+        for seed in 1..6 {
+            let cells = tree_cells(&grow(BonsaiStyle::Windswept, seed));
+            let left = cells.iter().filter(|&&(_, x)| x < 40).count();
+            assert!(left * 5 < cells.len(), "seed {}: {} of {} cells on the left", seed, left, cells.len());
+        }
+    }
+}
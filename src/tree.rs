@@ -0,0 +1,293 @@
+use std::fmt;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum BranchType {
+    Trunk,
+    ShootLeft,
+    ShootRight,
+    Dying,
+    Dead,
+    // spawned at leafy tips late in life with --blossoms and --fruit
+    Blossom,
+    Fruit,
+    // exposed roots (nebari) spreading over the rim of the pot with --roots
+    RootLeft,
+    RootRight,
+}
+
+impl BranchType {
+    pub fn from_i32(value: i32) -> BranchType {
+        match value {
+            0 => BranchType::Trunk,
+            1 => BranchType::ShootLeft,
+            2 => BranchType::ShootRight,
+            3 => BranchType::Dying,
+            4 => BranchType::Dead,
+            5 => BranchType::Blossom,
+            6 => BranchType::Fruit,
+            7 => BranchType::RootLeft,
+            8 => BranchType::RootRight,
+            _ => panic!("invalid branch i32 conversion"),
+        }
+    }
+}
+
+impl BranchType {
+    // trunk and shoots, everything else is foliage
+    pub fn is_wood(&self) -> bool {
+        matches!(self, BranchType::Trunk | BranchType::ShootLeft | BranchType::ShootRight)
+    }
+
+    pub fn is_root(&self) -> bool {
+        matches!(self, BranchType::RootLeft | BranchType::RootRight)
+    }
+
+    // whether its cells are foliage, wood that is nearly dead is drawn as leaves too (see choose_string)
+    pub fn draws_leaves(&self, life: i32) -> bool {
+        if self.is_root() {
+            false
+        } else if self.is_wood() {
+            life < 4
+        } else {
+            true
+        }
+    }
+}
+
+impl fmt::Display for BranchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            BranchType::Trunk => write!(f, "Trunk"),
+            BranchType::ShootLeft => write!(f, "ShootLeft"),
+            BranchType::ShootRight => write!(f, "ShootRight"),
+            BranchType::Dying => write!(f, "Dying"),
+            BranchType::Dead => write!(f, "Dead"),
+            BranchType::Blossom => write!(f, "Blossom"),
+            BranchType::Fruit => write!(f, "Fruit"),
+            BranchType::RootLeft => write!(f, "RootLeft"),
+            BranchType::RootRight => write!(f, "RootRight"),
+        }
+    }
+}
+
+// color is an index into the 16 color palette, the same number ncurses uses for its color pairs
+#[derive(PartialEq, Clone, Copy)]
+pub struct Style {
+    pub color: i16,
+    pub bold: bool,
+}
+
+#[derive(Clone, Copy)]
+pub struct Cell {
+    pub glyph: char,
+    pub branch_type: BranchType,
+    pub style: Style,
+    // nearly dead branches of any type draw leaves
+    pub leaf: bool,
+    // the branch that drew it, an index into the tree's branches
+    pub branch: Option<usize>,
+}
+
+impl Cell {
+    // trunk and shoot glyphs, the structure of the tree
+    pub fn is_wood(&self) -> bool {
+        !self.leaf
+    }
+}
+
+// where a branch started and what it grew from, so a branch can be cut along with everything growing off it
+#[derive(Clone, Copy)]
+pub struct BranchNode {
+    pub parent: Option<usize>,
+    pub branch_type: BranchType,
+    pub y: i32,
+    pub x: i32,
+    pub life: i32,
+}
+
+// the grown tree, a grid of cells in window coordinates (y down, x right)
+#[derive(Clone)]
+pub struct Tree {
+    height: i32,
+    width: i32,
+    cells: Vec<Option<Cell>>,
+    // per column, the first row branches can't grow into (the pot, or the bottom of the grid)
+    ground: Vec<i32>,
+    // per column, the top row of the earth in a pot, where roots can grow
+    soil: Vec<Option<i32>>,
+    // every branch ever started, parents always come before their children
+    branches: Vec<BranchNode>,
+}
+
+impl Tree {
+    pub fn new(height: i32, width: i32) -> Tree {
+        let height = height.max(0);
+        let width = width.max(0);
+
+        Tree {
+            height,
+            width,
+            cells: vec![None; (height * width) as usize],
+            ground: vec![height; width as usize],
+            soil: vec![None; width as usize],
+            branches: Vec::new(),
+        }
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn contains(&self, y: i32, x: i32) -> bool {
+        y >= 0 && y < self.height && x >= 0 && x < self.width
+    }
+
+    // columns off the grid have no ground to stand on, only the bottom
+    pub fn ground(&self, x: i32) -> i32 {
+        if x < 0 || x >= self.width {
+            return self.height;
+        }
+        self.ground[x as usize]
+    }
+
+    // raises the ground to row y for columns x_start..x_end, e.g. to keep branches out of the pot
+    pub fn set_ground(&mut self, x_start: i32, x_end: i32, y: i32) {
+        for x in x_start.max(0)..x_end.min(self.width) {
+            self.ground[x as usize] = y.clamp(0, self.height);
+        }
+    }
+
+    pub fn soil(&self, x: i32) -> Option<i32> {
+        if x < 0 || x >= self.width {
+            return None;
+        }
+        self.soil[x as usize]
+    }
+
+    // columns x_start..x_end have a pot whose earth starts at row y
+    pub fn set_soil(&mut self, x_start: i32, x_end: i32, y: i32) {
+        for x in x_start.max(0)..x_end.min(self.width) {
+            self.soil[x as usize] = Some(y);
+        }
+    }
+
+    pub fn get(&self, y: i32, x: i32) -> Option<&Cell> {
+        if !self.contains(y, x) {
+            return None;
+        }
+        self.cells[(y * self.width + x) as usize].as_ref()
+    }
+
+    pub fn set(&mut self, y: i32, x: i32, cell: Option<Cell>) {
+        if self.contains(y, x) {
+            self.cells[(y * self.width + x) as usize] = cell;
+        }
+    }
+
+    // writes s left to right starting at (y, x), anything outside the grid is dropped
+    pub fn put_str(&mut self, y: i32, x: i32, s: &str, branch_type: BranchType, style: Style) {
+        for (i, glyph) in s.chars().enumerate() {
+            let leaf = branch_type.draws_leaves(i32::MAX);
+            self.set(y, x + i as i32, Some(Cell { glyph, branch_type, style, leaf, branch: None }));
+        }
+    }
+
+    pub fn add_branch(&mut self, node: BranchNode) -> usize {
+        self.branches.push(node);
+        self.branches.len() - 1
+    }
+
+    pub fn branch(&self, id: usize) -> Option<&BranchNode> {
+        self.branches.get(id)
+    }
+
+    // cuts the branch that drew the cell at (y, x) and everything that grew off it, returns the branch that was cut
+    pub fn prune(&mut self, y: i32, x: i32) -> Option<BranchNode> {
+        let id = self.get(y, x)?.branch?;
+
+        // children always come after their parent, so one pass finds them all
+        let mut cut = vec![false; self.branches.len()];
+        cut[id] = true;
+        for i in id + 1..self.branches.len() {
+            cut[i] = self.branches[i].parent.is_some_and(|p| cut[p]);
+        }
+
+        for cell in self.cells.iter_mut() {
+            if cell.is_some_and(|c| c.branch.is_some_and(|b| cut[b])) {
+                *cell = None;
+            }
+        }
+
+        Some(self.branches[id])
+    }
+
+    // copies every grown cell of other into this tree, shifted right by x, along with its ground, soil and branches
+    pub fn paste(&mut self, other: &Tree, x: i32) {
+        let first = self.branches.len();
+        for node in &other.branches {
+            self.branches.push(BranchNode { parent: node.parent.map(|p| p + first), x: node.x + x, ..*node });
+        }
+
+        for ox in 0..other.width {
+            if x + ox < 0 || x + ox >= self.width {
+                continue;
+            }
+            self.ground[(x + ox) as usize] = other.ground(ox).min(self.height);
+            self.soil[(x + ox) as usize] = other.soil(ox);
+
+            for oy in 0..other.height {
+                if let Some(cell) = other.get(oy, ox) {
+                    self.set(oy, ox + x, Some(Cell { branch: cell.branch.map(|b| b + first), ..*cell }));
+                }
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.iter_mut().for_each(|c| *c = None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WOOD: Style = Style { color: 3, bold: false };
+
+    fn grow(tree: &mut Tree, parent: Option<usize>, y: i32, x: i32) -> usize {
+        let id = tree.add_branch(BranchNode { parent, branch_type: BranchType::Trunk, y, x, life: 10 });
+        tree.set(y, x, Some(Cell { glyph: '|', branch_type: BranchType::Trunk, style: WOOD, leaf: false, branch: Some(id) }));
+        id
+    }
+
+    #[test]
+    fn prune_cuts_everything_growing_off_the_branch() {
+        let mut tree = Tree::new(5, 5);
+        let trunk = grow(&mut tree, None, 4, 2);
+        let shoot = grow(&mut tree, Some(trunk), 3, 1);
+        grow(&mut tree, Some(shoot), 2, 0);
+        grow(&mut tree, Some(trunk), 3, 3);
+
+        let cut = tree.prune(3, 1).unwrap();
+        assert_eq!((cut.y, cut.x), (3, 1));
+        assert!(tree.get(3, 1).is_none());
+        assert!(tree.get(2, 0).is_none());
+        assert!(tree.get(4, 2).is_some());
+        assert!(tree.get(3, 3).is_some());
+    }
+
+    #[test]
+    fn prune_needs_a_branch() {
+        let mut tree = Tree::new(3, 3);
+        assert!(tree.prune(1, 1).is_none());
+
+        // the pot is drawn into the tree without a branch
+        tree.put_str(2, 0, "(_)", BranchType::Trunk, WOOD);
+        assert!(tree.prune(2, 1).is_none());
+        assert!(tree.get(2, 1).is_some());
+    }
+}
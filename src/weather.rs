@@ -0,0 +1,165 @@
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::tree::Style;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Weather {
+    Rain,
+    Snow,
+}
+
+// "none" is the same as no --weather at all
+pub const WEATHER_NAMES: &[(&str, Option<Weather>)] = &[
+    ("rain", Some(Weather::Rain)),
+    ("snow", Some(Weather::Snow)),
+    ("none", None),
+];
+
+impl Weather {
+    pub fn from_name(name: &str) -> Option<Option<Weather>> {
+        let name = name.trim().to_lowercase();
+        WEATHER_NAMES.iter().find(|(n, _)| *n == name).map(|(_, w)| *w)
+    }
+}
+
+impl fmt::Display for Weather {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = WEATHER_NAMES.iter().find(|(_, w)| *w == Some(*self)).map(|(n, _)| *n).unwrap_or("unknown");
+        write!(f, "{}", name)
+    }
+}
+
+// the rain or snow over a screen of height x width cells. it knows nothing about the tree,
+// whoever ticks it says which cells are taken
+pub struct Sky {
+    weather: Weather,
+    height: i32,
+    width: i32,
+    // falling drops or flakes
+    drops: Vec<(i32, i32)>,
+    // snow lying on something, per cell
+    settled: Vec<bool>,
+    rng: StdRng,
+}
+
+impl Sky {
+    pub fn new(weather: Weather, height: i32, width: i32, seed: u64) -> Sky {
+        let height = height.max(0);
+        let width = width.max(0);
+
+        Sky {
+            weather,
+            height,
+            width,
+            drops: Vec::new(),
+            settled: vec![false; (height * width) as usize],
+            // its own dice, the weather never changes how the tree grows
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    fn is_settled(&self, y: i32, x: i32) -> bool {
+        y >= 0 && y < self.height && x >= 0 && x < self.width && self.settled[(y * self.width + x) as usize]
+    }
+
+    // one frame: new drops start at the top and everything falls. rain falls two rows a frame and goes behind
+    // whatever is in the way, snow drifts down one row and stays on the first taken cell it lands on
+    pub fn tick(&mut self, taken: impl Fn(i32, i32) -> bool) {
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+
+        let (per_frame, speed) = match self.weather {
+            Weather::Rain => (self.width / 12 + 1, 2),
+            Weather::Snow => (self.width / 40 + 1, 1),
+        };
+        // rain starts on either of the top two rows so the drops don't all line up
+        for _ in 0..per_frame {
+            let y = self.rng.gen_range(0..speed);
+            let x = self.rng.gen_range(0..self.width);
+            self.drops.push((y, x));
+        }
+
+        let mut drops = std::mem::take(&mut self.drops);
+        drops.retain_mut(|(y, x)| {
+            if self.weather == Weather::Rain {
+                *y += speed;
+                return *y < self.height;
+            }
+
+            let next_x = (*x + self.rng.gen_range(-1..=1)).clamp(0, self.width - 1);
+            let below = *y + 1 >= self.height || taken(*y + 1, next_x);
+
+            // flakes lie on the tree, the pot and the bottom of the screen, one deep. more snow just melts into it
+            if below && !taken(*y, next_x) {
+                self.settled[(*y * self.width + next_x) as usize] = true;
+                return false;
+            }
+
+            *y += speed;
+            *x = next_x;
+            *y < self.height
+        });
+        self.drops = drops;
+    }
+
+    // what to draw where, all but the cells taken says the tree or pot are in
+    pub fn cells(&self, taken: impl Fn(i32, i32) -> bool) -> Vec<(i32, i32, char, Style)> {
+        let mut cells = Vec::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.is_settled(y, x) {
+                    cells.push((y, x, '.', Style { color: 15, bold: true }));
+                }
+            }
+        }
+
+        for &(y, x) in &self.drops {
+            match self.weather {
+                Weather::Rain => cells.push((y, x, '|', Style { color: 12, bold: false })),
+                Weather::Snow => cells.push((y, x, '*', Style { color: 15, bold: false })),
+            }
+        }
+
+        cells.retain(|&(y, x, _, _)| !taken(y, x));
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weather_stays_out_of_the_tree_and_pot() {
+        // a pot along the bottom and a trunk with a crown standing in it
+        let pot = |y: i32, x: i32| y >= 20 && (25..55).contains(&x);
+        let tree = |y: i32, x: i32| ((8..20).contains(&y) && (38..42).contains(&x)) || ((4..8).contains(&y) && (30..50).contains(&x));
+        let taken = |y: i32, x: i32| pot(y, x) || tree(y, x);
+
+        for weather in [Weather::Rain, Weather::Snow] {
+            let mut sky = Sky::new(weather, 24, 80, 3);
+            let mut shown = 0;
+            for _ in 0..200 {
+                sky.tick(taken);
+                for (y, x, _, _) in sky.cells(taken) {
+                    assert!(!taken(y, x), "{} over ({}, {})", weather, y, x);
+                    shown += 1;
+                }
+            }
+            assert!(shown > 0);
+        }
+
+        // snow that lay there before the tree grew over it is hidden too
+        let mut sky = Sky::new(Weather::Snow, 24, 80, 3);
+        for _ in 0..200 {
+            sky.tick(|_, _| false);
+        }
+        assert!(sky.cells(|_, _| false).iter().any(|&(y, x, _, _)| pot(y, x)));
+        assert!(sky.cells(taken).iter().all(|&(y, x, _, _)| !taken(y, x)));
+    }
+}
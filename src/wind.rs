@@ -0,0 +1,133 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::rules::{BranchState, GrowthRules, Spawn};
+use crate::tree::{BranchType, Style};
+
+// pushes branches sideways on top of some other rules.
+// wind (-1.0 left .. 1.0 right) is the chance per step that a branch gets pushed one cell further that way,
+// trunks feel half of it plus the lean, leaves feel the most
+pub struct WindRules<'a> {
+    growth: &'a dyn GrowthRules,
+    wind: f32,
+    lean: f32,
+    gusts: bool,
+}
+
+impl<'a> WindRules<'a> {
+    pub fn new(growth: &'a dyn GrowthRules, wind: f32, lean: f32, gusts: bool) -> WindRules<'a> {
+        WindRules { growth, wind, lean, gusts }
+    }
+
+    // gusts swell and die down as the tree grows, between 0.4 and 1.6 times the wind.
+    // the clock is the tree's own steps, so trees growing side by side each get their own gusts
+    fn wind_now(&self, branch: &BranchState) -> f32 {
+        if !self.gusts {
+            return self.wind;
+        }
+
+        let step = branch.step as f32;
+        self.wind * (1.0 + 0.6 * (step / 20.0).sin() * (step / 7.0).sin())
+    }
+}
+
+impl GrowthRules for WindRules<'_> {
+    fn deltas(&self, rng: &mut StdRng, branch: &BranchState) -> (i32, i32) {
+        let (mut dx, dy) = self.growth.deltas(rng, branch);
+
+        let wind = self.wind_now(branch);
+        let push = match branch.branch_type {
+            BranchType::Trunk => wind * 0.5 + self.lean,
+            BranchType::ShootLeft | BranchType::ShootRight => wind,
+            BranchType::Dying | BranchType::Dead | BranchType::Blossom | BranchType::Fruit => wind * 1.5,
+            // down in the pot, out of the wind
+            BranchType::RootLeft | BranchType::RootRight => 0.0,
+        };
+
+        if push != 0.0 && rng.gen::<f32>() < push.abs().min(1.0) {
+            dx += if push > 0.0 { 1 } else { -1 };
+        }
+
+        (dx, dy)
+    }
+
+    fn decay(&self, branch: &BranchState) -> Option<BranchType> {
+        self.growth.decay(branch)
+    }
+
+    fn spawn(&self, rng: &mut StdRng, branch: &BranchState) -> Spawn {
+        self.growth.spawn(rng, branch)
+    }
+
+    fn shoot_cooldown(&self, multiplier: i32) -> i32 {
+        self.growth.shoot_cooldown(multiplier)
+    }
+
+    fn style(&self, rng: &mut StdRng, branch_type: BranchType) -> Style {
+        self.growth.style(rng, branch_type)
+    }
+
+    fn glyphs(&self, rng: &mut StdRng, branch: &BranchState, dx: i32, dy: i32, leaves: &[char]) -> String {
+        self.growth.glyphs(rng, branch, dx, dy, leaves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+    use crate::growth::Growth;
+    use crate::rules::CbonsaiRules;
+    use crate::tree::Tree;
+
+    // the mean column of the cells of the types counts grows from seed with this wind and lean
+    fn mean_x(seed: u64, wind: f32, lean: f32, counts: impl Fn(BranchType) -> bool) -> f32 {
+        let mut conf = Config::default();
+        crate::args::default_leaves(&mut conf);
+        let rules = WindRules::new(&CbonsaiRules, wind, lean, false);
+        let mut growth = Growth::seeded(&conf, seed, Tree::new(30, 80), &rules);
+        growth.by_ref().for_each(drop);
+
+        let tree = growth.tree();
+        let xs: Vec<i32> = (0..30).flat_map(|y| (0..80).map(move |x| (y, x)))
+            .filter(|&(y, x)| tree.get(y, x).is_some_and(|c| counts(c.branch_type)))
+            .map(|(_, x)| x)
+            .collect();
+        xs.iter().sum::<i32>() as f32 / xs.len() as f32
+    }
+
+    fn branch(step: i32, x: i32) -> BranchState {
+        BranchState { branch_type: BranchType::ShootLeft, y: 10, x, life: 20, age: 12, multiplier: 5, shoot_cooldown: 0, step, blossoms: 0.0, fruit: 0.0 }
+    }
+
+    #[test]
+    fn gusts_come_and_go_as_the_tree_grows() {
+        let wind = WindRules::new(&CbonsaiRules, 0.5, 0.0, true);
+        let strengths: Vec<f32> = (0..200).map(|step| wind.wind_now(&branch(step, 40))).collect();
+        let (low, high) = strengths.iter().fold((f32::MAX, f32::MIN), |(lo, hi), &w| (lo.min(w), hi.max(w)));
+        assert!(low < 0.4 && high > 0.6, "gusts between {} and {}", low, high);
+        assert!(strengths.iter().all(|w| (0.2..=0.8).contains(w)));
+
+        // a step of growth blows the same on every branch
+        assert_eq!(wind.wind_now(&branch(30, 5)), wind.wind_now(&branch(30, 70)));
+        assert_eq!(WindRules::new(&CbonsaiRules, 0.5, 0.0, false).wind_now(&branch(30, 5)), 0.5);
+    }
+
+    #[test]
+    fn the_wind_blows_the_tree_its_way() {
+        for seed in 1..6 {
+            let branches = |wind| mean_x(seed, wind, 0.0, |t| t != BranchType::Trunk);
+            let (left, still, right) = (branches(-1.0), branches(0.0), branches(1.0));
+            assert!(left < still && still < right, "seed {}: {} {} {}", seed, left, still, right);
+        }
+    }
+
+    #[test]
+    fn lean_tilts_the_trunk() {
+        for seed in 1..6 {
+            let trunk = |lean| mean_x(seed, 0.0, lean, |t| t == BranchType::Trunk);
+            let (left, still, right) = (trunk(-0.5), trunk(0.0), trunk(0.5));
+            assert!(left < still && still < right, "seed {}: {} {} {}", seed, left, still, right);
+        }
+    }
+}